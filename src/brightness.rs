@@ -0,0 +1,105 @@
+use hal::blocking::i2c;
+
+use cmd::Command;
+use {Error, Ssd1306};
+
+/// Display brightness, set through both the precharge period and contrast
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brightness {
+    pre_charge: (u8, u8),
+    contrast: u8,
+}
+
+impl Brightness {
+    /// Dimmest brightness
+    pub const DIMMEST: Brightness = Brightness::custom(0x1, 0x1, 0x00);
+    /// Dim brightness
+    pub const DIM: Brightness = Brightness::custom(0x1, 0x4, 0x2F);
+    /// Normal brightness, as set by the default display configuration
+    pub const NORMAL: Brightness = Brightness::custom(0x1, 0xF, 0x8F);
+    /// Bright brightness
+    pub const BRIGHT: Brightness = Brightness::custom(0x1, 0xF, 0xBF);
+    /// Brightest brightness
+    pub const BRIGHTEST: Brightness = Brightness::custom(0x1, 0xF, 0xFF);
+
+    /// Custom brightness. Precharge phases are each from 1-15.
+    pub const fn custom(phase1: u8, phase2: u8, contrast: u8) -> Brightness {
+        Brightness {
+            pre_charge: (phase1, phase2),
+            contrast,
+        }
+    }
+
+    fn lerp(&self, to: &Brightness, step: u8, steps: u8) -> Brightness {
+        let lerp = |from: u8, to: u8| {
+            let delta = (to as i32 - from as i32) * step as i32 / steps as i32;
+            (from as i32 + delta) as u8
+        };
+
+        Brightness::custom(
+            lerp(self.pre_charge.0, to.pre_charge.0),
+            lerp(self.pre_charge.1, to.pre_charge.1),
+            lerp(self.contrast, to.contrast),
+        )
+    }
+}
+
+/// Brightness ramp in progress
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Ramp {
+    from: Brightness,
+    to: Brightness,
+    step: u8,
+    steps: u8,
+}
+
+impl<I2C> Ssd1306<I2C>
+where
+    I2C: i2c::Write,
+{
+    /// Set display brightness, stopping any brightness ramp
+    pub fn set_brightness(&mut self, brightness: Brightness) -> Result<(), Error<I2C::Error>> {
+        self.ramp = None;
+        self.apply_brightness(brightness)
+    }
+
+    /// Start ramping brightness towards `target` over `steps` calls to
+    /// [`step_brightness`](#method.step_brightness)
+    pub fn ramp_brightness(&mut self, target: Brightness, steps: u8) {
+        self.ramp = Some(Ramp {
+            from: self.brightness,
+            to: target,
+            step: 0,
+            steps,
+        });
+    }
+
+    /// Advance the brightness ramp by one step, usually from a timer tick.
+    /// Returns whether the ramp has more steps to go.
+    pub fn step_brightness(&mut self) -> Result<bool, Error<I2C::Error>> {
+        let mut ramp = match self.ramp {
+            Some(ramp) => ramp,
+            None => return Ok(false),
+        };
+
+        ramp.step += 1;
+        let brightness = if ramp.step >= ramp.steps {
+            ramp.to
+        } else {
+            ramp.from.lerp(&ramp.to, ramp.step, ramp.steps)
+        };
+        self.apply_brightness(brightness)?;
+
+        let more = ramp.step < ramp.steps;
+        self.ramp = if more { Some(ramp) } else { None };
+        Ok(more)
+    }
+
+    fn apply_brightness(&mut self, brightness: Brightness) -> Result<(), Error<I2C::Error>> {
+        let (phase1, phase2) = brightness.pre_charge;
+        self.send_command(Command::pre_charge_period(phase1, phase2)?)?;
+        self.send_command(Command::Contrast(brightness.contrast))?;
+        self.brightness = brightness;
+        Ok(())
+    }
+}
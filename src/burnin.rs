@@ -0,0 +1,249 @@
+use hal::blocking::i2c;
+
+use cmd::Command;
+use {Error, Ssd1306};
+
+/// Rectangular display region, in pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    /// Left column
+    pub x: u8,
+    /// Top row
+    pub y: u8,
+    /// Width
+    pub width: u8,
+    /// Height
+    pub height: u8,
+}
+
+impl Region {
+    /// Bits of a column byte in `page` covered by this region
+    fn page_mask(&self, page: u8) -> u8 {
+        let top = page as u16 * 8;
+        let start = (self.y as u16).max(top);
+        let end = (self.y as u16 + self.height as u16).min(top + 8);
+        if start >= end {
+            return 0;
+        }
+        let bits = (end - start) as u8;
+        ((0xFFu16 >> (8 - bits)) << (start - top)) as u8
+    }
+
+    fn contains_col(&self, col: usize) -> bool {
+        col >= self.x as usize && col < self.x as usize + self.width as usize
+    }
+}
+
+/// Anti burn-in settings. Intervals are counted in calls to
+/// [`Ssd1306::burn_in_tick`](../struct.Ssd1306.html#method.burn_in_tick)
+/// and disabled when 0.
+#[derive(Debug, Clone, Copy)]
+pub struct BurnInConfig {
+    shift_interval: u32,
+    max_shift: u8,
+    invert_interval: u32,
+    regions: &'static [Region],
+    blank_after: u32,
+}
+
+impl BurnInConfig {
+    /// All mitigations disabled
+    pub fn new() -> BurnInConfig {
+        BurnInConfig {
+            shift_interval: 0,
+            max_shift: 0,
+            invert_interval: 0,
+            regions: &[],
+            blank_after: 0,
+        }
+    }
+
+    /// Shift the image around by up to `max` pixels, one step every `interval` ticks
+    pub fn shift(mut self, interval: u32, max: u8) -> BurnInConfig {
+        self.shift_interval = interval;
+        self.max_shift = max;
+        self
+    }
+
+    /// Invert `regions` every `interval` ticks
+    pub fn invert(mut self, interval: u32, regions: &'static [Region]) -> BurnInConfig {
+        self.invert_interval = interval;
+        self.regions = regions;
+        self
+    }
+
+    /// Blank the display after `ticks` ticks without a changed
+    /// [`draw`](../struct.Ssd1306.html#method.draw)
+    pub fn blank_after(mut self, ticks: u32) -> BurnInConfig {
+        self.blank_after = ticks;
+        self
+    }
+}
+
+impl Default for BurnInConfig {
+    fn default() -> BurnInConfig {
+        BurnInConfig::new()
+    }
+}
+
+/// Anti burn-in state
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BurnIn {
+    config: BurnInConfig,
+    ticks: u32,
+    idle: u32,
+    step: u16,
+    shift: (u8, u8),
+    inverted: bool,
+    blanked: bool,
+}
+
+impl BurnIn {
+    /// Whether a page must be altered before being written to the display
+    pub(crate) fn alters(&self, page: u8) -> bool {
+        self.shift.0 != 0
+            || (self.inverted && self.config.regions.iter().any(|r| r.page_mask(page) != 0))
+    }
+
+    /// Rows below the image the vertical shift can bring into view
+    pub(crate) fn rows_below(&self) -> u8 {
+        if self.config.shift_interval != 0 {
+            self.config.max_shift
+        } else {
+            0
+        }
+    }
+
+    /// Shift and invert one page of the buffer into `out`
+    pub(crate) fn alter(&self, page: u8, row: &[u8], out: &mut [u8]) {
+        let dx = self.shift.0 as usize;
+        for col in 0..out.len() {
+            out[col] = if col < dx { 0 } else { row[col - dx] };
+            if self.inverted {
+                for region in self.config.regions.iter().filter(|r| r.contains_col(col)) {
+                    out[col] ^= region.page_mask(page);
+                }
+            }
+        }
+    }
+}
+
+/// Position along a square orbit of side `max` after `step` steps
+fn orbit(step: u16, max: u8) -> (u8, u8) {
+    let max = max as u16;
+    if max == 0 {
+        return (0, 0);
+    }
+    let i = step % (4 * max);
+    let (x, y) = match i / max {
+        0 => (i, 0),
+        1 => (max, i - max),
+        2 => (3 * max - i, max),
+        _ => (0, 4 * max - i),
+    };
+    (x as u8, y as u8)
+}
+
+impl<I2C> Ssd1306<I2C>
+where
+    I2C: i2c::Write,
+{
+    /// Enable anti burn-in mitigations, driven by
+    /// [`burn_in_tick`](#method.burn_in_tick)
+    pub fn enable_burn_in(&mut self, config: BurnInConfig) -> Result<(), Error<I2C::Error>> {
+        self.disable_burn_in()?;
+        let pages = self.page_mask();
+        self.burn_in = Some(BurnIn {
+            config,
+            ticks: 0,
+            idle: 0,
+            step: 0,
+            shift: (0, 0),
+            inverted: false,
+            blanked: false,
+        });
+
+        // Blank the rows below the image before the first shift shows them,
+        // leaving pages changed since the last draw for the next one
+        let extra = self.page_mask() & !pages;
+        if extra != 0 {
+            let dirty = self.dirty;
+            self.dirty = extra;
+            self.flush()?;
+            self.dirty |= dirty;
+        }
+        Ok(())
+    }
+
+    /// Disable anti burn-in mitigations and restore the unshifted image
+    pub fn disable_burn_in(&mut self) -> Result<(), Error<I2C::Error>> {
+        if let Some(state) = self.burn_in.take() {
+            if state.shift.1 != 0 {
+                let offset = self.display_offset;
+                self.send_command(Command::display_offset(offset)?)?;
+            }
+            if state.blanked {
+                self.send_command(Command::DisplayOn(true))?;
+            }
+            self.dirty = 0xFF;
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Advance anti burn-in timers by one tick, shifting, inverting or
+    /// blanking the display when due
+    pub fn burn_in_tick(&mut self) -> Result<(), Error<I2C::Error>> {
+        let mut state = match self.burn_in {
+            Some(state) => state,
+            None => return Ok(()),
+        };
+        let config = state.config;
+        state.ticks = state.ticks.wrapping_add(1);
+        state.idle = state.idle.saturating_add(1);
+
+        let mut refresh = false;
+        if config.shift_interval != 0 && state.ticks % config.shift_interval == 0 {
+            state.step = state.step.wrapping_add(1);
+            let shift = orbit(state.step, config.max_shift);
+            if shift.1 != state.shift.1 {
+                let offset = (self.display_offset + shift.1) % 64;
+                self.send_command(Command::display_offset(offset)?)?;
+            }
+            refresh |= shift.0 != state.shift.0;
+            state.shift = shift;
+        }
+        if config.invert_interval != 0 && state.ticks % config.invert_interval == 0 {
+            state.inverted = !state.inverted;
+            refresh |= !config.regions.is_empty();
+        }
+        if config.blank_after != 0 && state.idle >= config.blank_after && !state.blanked {
+            self.send_command(Command::DisplayOn(false))?;
+            state.blanked = true;
+        }
+
+        self.burn_in = Some(state);
+        if refresh {
+            self.dirty = 0xFF;
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Note a changed frame, restarting the idle timer and unblanking the display
+    pub(crate) fn burn_in_activity(&mut self) -> Result<(), Error<I2C::Error>> {
+        let blanked = match self.burn_in {
+            Some(ref mut state) => {
+                state.idle = 0;
+                let blanked = state.blanked;
+                state.blanked = false;
+                blanked
+            }
+            None => false,
+        };
+        if blanked {
+            self.send_command(Command::DisplayOn(true))?;
+        }
+        Ok(())
+    }
+}
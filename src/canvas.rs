@@ -0,0 +1,191 @@
+use hal::blocking::i2c;
+
+use cmd::OutOfRange;
+use {Error, Ssd1306};
+
+/// How drawing changes pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Color {
+    /// Turn pixels on
+    On,
+    /// Turn pixels off
+    Off,
+    /// Swap pixel values
+    Invert,
+}
+
+impl Color {
+    /// Apply color to the bits of `byte` set in `mask`
+    pub fn apply(self, byte: u8, mask: u8) -> u8 {
+        match self {
+            Color::On => byte | mask,
+            Color::Off => byte & !mask,
+            Color::Invert => byte ^ mask,
+        }
+    }
+}
+
+/// Surface for the drawing functions, organized like display RAM in pages
+/// of 8 rows with one byte per column
+pub trait Canvas {
+    /// Width and height in pixels
+    fn size(&self) -> (u16, u16);
+
+    /// Byte at column `x` of `page`
+    fn byte(&self, x: u16, page: u16) -> u8;
+
+    /// Apply `color` to the pixels set in `mask` of the byte at column `x` of `page`
+    fn apply_mask(&mut self, x: u16, page: u16, mask: u8, color: Color);
+
+    /// Apply `color` to a pixel, ignoring pixels outside the canvas
+    fn set_pixel(&mut self, x: i32, y: i32, color: Color) {
+        let (width, height) = self.size();
+        if x >= 0 && y >= 0 && x < width as i32 && y < height as i32 {
+            self.apply_mask(x as u16, y as u16 / 8, 1 << (y % 8), color);
+        }
+    }
+
+    /// Whether a pixel is on. Pixels outside the canvas are off.
+    fn get_pixel(&self, x: i32, y: i32) -> bool {
+        let (width, height) = self.size();
+        x >= 0 && y >= 0 && x < width as i32 && y < height as i32
+            && self.byte(x as u16, y as u16 / 8) & (1 << (y % 8)) != 0
+    }
+}
+
+/// Canvas in caller provided memory, which may be larger than the display
+pub struct VirtualCanvas<'a> {
+    buf: &'a mut [u8],
+    width: u16,
+    height: u16,
+}
+
+impl<'a> VirtualCanvas<'a> {
+    /// Create canvas in `buf`, which needs a byte per column for every started page
+    pub fn new(buf: &'a mut [u8], width: u16, height: u16) -> Result<VirtualCanvas<'a>, OutOfRange> {
+        if buf.len() < width as usize * ((height as usize + 7) >> 3) {
+            return Err(OutOfRange);
+        }
+        Ok(VirtualCanvas { buf, width, height })
+    }
+
+    /// Turn all pixels off
+    pub fn clear(&mut self) {
+        for b in self.buf.iter_mut() {
+            *b = 0;
+        }
+    }
+
+    /// Columns of `page` from `x` on
+    fn row(&self, x: u16, page: u16) -> &[u8] {
+        let start = page as usize * self.width as usize;
+        &self.buf[start + x as usize..start + self.width as usize]
+    }
+}
+
+impl<'a> Canvas for VirtualCanvas<'a> {
+    fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    fn byte(&self, x: u16, page: u16) -> u8 {
+        self.buf[page as usize * self.width as usize + x as usize]
+    }
+
+    fn apply_mask(&mut self, x: u16, page: u16, mask: u8, color: Color) {
+        let b = &mut self.buf[page as usize * self.width as usize + x as usize];
+        *b = color.apply(*b, mask);
+    }
+}
+
+impl<I2C> Canvas for Ssd1306<I2C>
+where
+    I2C: i2c::Write,
+{
+    fn size(&self) -> (u16, u16) {
+        // Scroll offset modes draw to all of display RAM
+        match self.vscroll {
+            Some(_) => (self.width as u16, 64),
+            None => (self.width as u16, self.height as u16),
+        }
+    }
+
+    fn byte(&self, x: u16, page: u16) -> u8 {
+        let row = self.ram_row(page as u8 * 8);
+        if row & 7 == 0 {
+            return self.buf[(row / 8) as usize * 128 + x as usize];
+        }
+        let mut byte = 0;
+        for bit in 0..8 {
+            let row = self.ram_row(page as u8 * 8 + bit);
+            if self.buf[(row / 8) as usize * 128 + x as usize] & (1 << (row % 8)) != 0 {
+                byte |= 1 << bit;
+            }
+        }
+        byte
+    }
+
+    fn apply_mask(&mut self, x: u16, page: u16, mask: u8, color: Color) {
+        let row = self.ram_row(page as u8 * 8);
+        if row & 7 == 0 {
+            let b = &mut self.buf[(row / 8) as usize * 128 + x as usize];
+            *b = color.apply(*b, mask);
+            self.dirty |= 1 << (row / 8);
+            return;
+        }
+        // Ring buffer offsets that are not page aligned split the byte
+        for bit in 0..8 {
+            if mask & (1 << bit) != 0 {
+                let row = self.ram_row(page as u8 * 8 + bit);
+                let b = &mut self.buf[(row / 8) as usize * 128 + x as usize];
+                *b = color.apply(*b, 1 << (row % 8));
+                self.dirty |= 1 << (row / 8);
+            }
+        }
+    }
+}
+
+impl<I2C> Ssd1306<I2C>
+where
+    I2C: i2c::Write,
+{
+    /// Write the part of `canvas` from column `x` and row `y` that fits the
+    /// display straight to display RAM, starting at its first row. The buffer
+    /// is left untouched and only written again for pages changed after this.
+    /// Burn-in shifting and inversion apply as for the buffer, a vertical
+    /// scroll offset is not supported.
+    pub fn draw_viewport(&mut self, canvas: &VirtualCanvas, x: u16, y: u16) -> Result<(), Error<I2C::Error>> {
+        let (width, height) = canvas.size();
+        if x as u32 + self.width as u32 > width as u32 || y as u32 + self.height as u32 > height as u32 {
+            return Err(Error::OutOfRange);
+        }
+        if self.vscroll.is_some() {
+            return Err(Error::Unsupported);
+        }
+
+        let shift = y % 8;
+        let pages = 0xFF >> (8 - self.height / 8);
+        self.pause_scroll(|disp| {
+            disp.write_pages(pages, |disp, page| {
+                let src = y / 8 + page as u16;
+                let cols = disp.width as usize;
+
+                // Rows of the page come from the bottom of one canvas page and the top of the next
+                let mut bytes = [0; 128];
+                let lo = canvas.row(x, src);
+                for (b, l) in bytes[..cols].iter_mut().zip(lo) {
+                    *b = l >> shift;
+                }
+                if shift != 0 && (src + 1) * 8 < height {
+                    let hi = canvas.row(x, src + 1);
+                    for (b, h) in bytes[..cols].iter_mut().zip(hi) {
+                        *b |= h << (8 - shift);
+                    }
+                }
+                disp.write_row(page, &bytes[..cols])
+            })
+        })?;
+        self.dirty = 0;
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 /// SSD1306 Commands
 
+use controller::Controller;
 use prelude::*;
 
 /// Commands
@@ -23,6 +24,10 @@ pub enum Command {
     /// number of frames per step, and vertical scrolling offset.
     /// Scrolling offset may be from 0-63
     VHScrollSetup(VHScrollDir, Page, Page, NFrames, u8),
+    /// Scroll content of a window by one column.
+    /// Values are scroll direction, start page, end page,
+    /// start column and end column. Columns may be from 0-127
+    ContentScroll(HScrollDir, Page, Page, u8, u8),
     /// Enable scrolling
     EnableScroll(bool),
     /// Setup vertical scroll area.
@@ -69,7 +74,7 @@ pub enum Command {
     /// First value is oscillator frequency, increasing with higher value
     /// Second value is divide ratio - 1
     DisplayClockDiv(u8, u8),
-    /// Set up phase 1 and 2 of precharge period. each value is from 0-63
+    /// Set up phase 1 and 2 of precharge period. each value is from 1-15
     PreChargePeriod(u8, u8),
     /// Set Vcomh Deselect level
     VcomhDeselect(VcomhLevel),
@@ -77,9 +82,139 @@ pub enum Command {
     Noop,
     /// Enable charge pump
     ChargePump(bool),
+    /// Set up fade out or blinking.
+    /// Values are fade mode and number of frames per brightness step.
+    FadeOutBlink(FadeMode, FadeInterval),
+    /// Enable zoom in. Requires alternative COM pin configuration.
+    ZoomIn(bool),
+    /// Enable DC-DC converter. SH1106 only.
+    DcDc(bool),
+    /// Select external VCC supply. SSD1305 only.
+    ExternalVcc,
+}
+
+/// Command argument outside of the range accepted by the display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange;
+
+fn check(val: u8, min: u8, max: u8) -> Result<(), OutOfRange> {
+    if val < min || val > max {
+        Err(OutOfRange)
+    } else {
+        Ok(())
+    }
 }
 
 impl Command {
+    /// Set up horizontal scrolling, checking that the end page
+    /// is not before the start page
+    pub fn hscroll_setup(
+        dir: HScrollDir,
+        start: Page,
+        end: Page,
+        rate: NFrames,
+    ) -> Result<Command, OutOfRange> {
+        check(end as u8, start as u8, Page::Page7 as u8)?;
+        Ok(Command::HScrollSetup(dir, start, end, rate))
+    }
+
+    /// Set up horizontal + vertical scrolling, checking that the end
+    /// page is not before the start page and the offset is from 0-63
+    pub fn vhscroll_setup(
+        dir: VHScrollDir,
+        start: Page,
+        end: Page,
+        rate: NFrames,
+        offset: u8,
+    ) -> Result<Command, OutOfRange> {
+        check(end as u8, start as u8, Page::Page7 as u8)?;
+        check(offset, 0, 63)?;
+        Ok(Command::VHScrollSetup(dir, start, end, rate, offset))
+    }
+
+    /// Scroll content of a window by one column, checking that the end page
+    /// and column are not before the start page and column and the end
+    /// column is from 0-127
+    pub fn content_scroll(
+        dir: HScrollDir,
+        start: Page,
+        end: Page,
+        start_col: u8,
+        end_col: u8,
+    ) -> Result<Command, OutOfRange> {
+        check(end as u8, start as u8, Page::Page7 as u8)?;
+        check(end_col, 0, 127)?;
+        check(start_col, 0, end_col)?;
+        Ok(Command::ContentScroll(dir, start, end, start_col, end_col))
+    }
+
+    /// Setup vertical scroll area, checking that rows above are from 0-63,
+    /// scrolling rows are from 0-64 and both together fit in 64 rows
+    pub fn vscroll_area(above: u8, lines: u8) -> Result<Command, OutOfRange> {
+        check(above, 0, 63)?;
+        check(lines, 0, 64 - above)?;
+        Ok(Command::VScrollArea(above, lines))
+    }
+
+    /// Set the lower nibble of the column start address, checking it is from 0-15
+    pub fn lower_col_start(nibble: u8) -> Result<Command, OutOfRange> {
+        check(nibble, 0, 0xF)?;
+        Ok(Command::LowerColStart(nibble))
+    }
+
+    /// Set the upper nibble of the column start address, checking it is from 0-8.
+    /// Only controllers with 132 column RAM accept 8.
+    pub fn upper_col_start(nibble: u8) -> Result<Command, OutOfRange> {
+        check(nibble, 0, 0x8)?;
+        Ok(Command::UpperColStart(nibble))
+    }
+
+    /// Setup column start and end address, checking both are from 0-127
+    /// and the end is not before the start
+    pub fn column_address(start: u8, end: u8) -> Result<Command, OutOfRange> {
+        Command::column_address_for(Controller::Ssd1306, start, end)
+    }
+
+    /// Setup column start and end address, checking both are within the
+    /// display RAM of `controller` and the end is not before the start
+    pub fn column_address_for(controller: Controller, start: u8, end: u8) -> Result<Command, OutOfRange> {
+        check(end, 0, controller.columns() - 1)?;
+        check(start, 0, end)?;
+        Ok(Command::ColumnAddress(start, end))
+    }
+
+    /// Set display start line, checking it is from 0-63
+    pub fn start_line(line: u8) -> Result<Command, OutOfRange> {
+        check(line, 0, 63)?;
+        Ok(Command::StartLine(line))
+    }
+
+    /// Set multiplex ratio, checking it is from 15-63
+    pub fn multiplex(ratio: u8) -> Result<Command, OutOfRange> {
+        check(ratio, 15, 63)?;
+        Ok(Command::Multiplex(ratio))
+    }
+
+    /// Set vertical shift, checking it is from 0-63
+    pub fn display_offset(offset: u8) -> Result<Command, OutOfRange> {
+        check(offset, 0, 63)?;
+        Ok(Command::DisplayOffset(offset))
+    }
+
+    /// Set up display clock, checking both values are from 0-15
+    pub fn display_clock_div(fosc: u8, div: u8) -> Result<Command, OutOfRange> {
+        check(fosc, 0, 0xF)?;
+        check(div, 0, 0xF)?;
+        Ok(Command::DisplayClockDiv(fosc, div))
+    }
+
+    /// Set up phase 1 and 2 of precharge period, checking both are from 1-15
+    pub fn pre_charge_period(phase1: u8, phase2: u8) -> Result<Command, OutOfRange> {
+        check(phase1, 1, 0xF)?;
+        check(phase2, 1, 0xF)?;
+        Ok(Command::PreChargePeriod(phase1, phase2))
+    }
+
     /// Send command to SSD1306
     pub fn send<I2C>(&self, i2c: &mut I2C, addr: u8) -> Result<(), I2C::Error>
     where
@@ -116,6 +251,15 @@ impl Command {
                 i2c.write_cmd(addr, end as u8)?;
                 i2c.write_cmd(addr, offset)?;
             }
+            Command::ContentScroll(dir, start, end, start_col, end_col) => {
+                i2c.write_cmd(addr, 0x2C | (dir as u8))?;
+                i2c.write_cmd(addr, 0)?;
+                i2c.write_cmd(addr, start as u8)?;
+                i2c.write_cmd(addr, 0x01)?;
+                i2c.write_cmd(addr, end as u8)?;
+                i2c.write_cmd(addr, start_col)?;
+                i2c.write_cmd(addr, end_col)?;
+            }
             Command::EnableScroll(en) => {
                 i2c.write_cmd(addr, 0x2E | (en as u8))?;
             }
@@ -124,11 +268,11 @@ impl Command {
                 i2c.write_cmd(addr, above)?;
                 i2c.write_cmd(addr, lines)?;
             }
-            Command::LowerColStart(addr) => {
-                i2c.write_cmd(addr, 0xF & addr)?;
+            Command::LowerColStart(nibble) => {
+                i2c.write_cmd(addr, 0xF & nibble)?;
             }
-            Command::UpperColStart(addr) => {
-                i2c.write_cmd(addr, 0x1F & addr)?;
+            Command::UpperColStart(nibble) => {
+                i2c.write_cmd(addr, 0x10 | (0xF & nibble))?;
             }
             Command::AddressMode(mode) => {
                 i2c.write_cmd(addr, 0x20)?;
@@ -187,6 +331,22 @@ impl Command {
                 i2c.write_cmd(addr, 0x8D)?;
                 i2c.write_cmd(addr, 0x10 | ((en as u8) << 2))?;
             }
+            Command::FadeOutBlink(mode, interval) => {
+                i2c.write_cmd(addr, 0x23)?;
+                i2c.write_cmd(addr, ((mode as u8) << 4) | (interval as u8))?;
+            }
+            Command::ZoomIn(en) => {
+                i2c.write_cmd(addr, 0xD6)?;
+                i2c.write_cmd(addr, en as u8)?;
+            }
+            Command::DcDc(en) => {
+                i2c.write_cmd(addr, 0xAD)?;
+                i2c.write_cmd(addr, 0x8A | (en as u8))?;
+            }
+            Command::ExternalVcc => {
+                i2c.write_cmd(addr, 0xAD)?;
+                i2c.write_cmd(addr, 0x8E)?;
+            }
         }
 
         Ok(())
@@ -292,3 +452,51 @@ pub enum VcomhLevel {
     /// Auto
     Auto = 0b100,
 }
+
+/// Fade out and blinking mode
+#[derive(Debug, Clone, Copy)]
+pub enum FadeMode {
+    /// Disable fade out and blinking
+    Disabled = 0b00,
+    /// Fade out once
+    FadeOut = 0b10,
+    /// Blink continuously
+    Blink = 0b11,
+}
+
+/// Fade out and blinking interval, in frames per brightness step
+#[derive(Debug, Clone, Copy)]
+pub enum FadeInterval {
+    /// 8 Frames
+    F8 = 0b0000,
+    /// 16 Frames
+    F16 = 0b0001,
+    /// 24 Frames
+    F24 = 0b0010,
+    /// 32 Frames
+    F32 = 0b0011,
+    /// 40 Frames
+    F40 = 0b0100,
+    /// 48 Frames
+    F48 = 0b0101,
+    /// 56 Frames
+    F56 = 0b0110,
+    /// 64 Frames
+    F64 = 0b0111,
+    /// 72 Frames
+    F72 = 0b1000,
+    /// 80 Frames
+    F80 = 0b1001,
+    /// 88 Frames
+    F88 = 0b1010,
+    /// 96 Frames
+    F96 = 0b1011,
+    /// 104 Frames
+    F104 = 0b1100,
+    /// 112 Frames
+    F112 = 0b1101,
+    /// 120 Frames
+    F120 = 0b1110,
+    /// 128 Frames
+    F128 = 0b1111,
+}
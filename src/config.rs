@@ -0,0 +1,152 @@
+use cmd::VcomhLevel;
+use controller::Controller;
+use {FlushMode, Resolution};
+
+/// Display settings applied by
+/// [`Ssd1306::init_with`](../struct.Ssd1306.html#method.init_with)
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayConfig {
+    pub(crate) clock_div: (u8, u8),
+    pub(crate) multiplex: u8,
+    pub(crate) display_offset: u8,
+    pub(crate) start_line: u8,
+    pub(crate) charge_pump: bool,
+    pub(crate) flush_mode: FlushMode,
+    pub(crate) column_offset: u8,
+    pub(crate) segment_remap: bool,
+    pub(crate) reverse_com_dir: bool,
+    pub(crate) com_pins: (bool, bool),
+    pub(crate) contrast: u8,
+    pub(crate) pre_charge: (u8, u8),
+    pub(crate) vcomh: VcomhLevel,
+    pub(crate) invert: bool,
+}
+
+impl DisplayConfig {
+    /// Default settings for a display controller and resolution
+    pub fn new(controller: Controller, res: Resolution) -> DisplayConfig {
+        let (height, com_alt) = match res {
+            Resolution::R128x32 => (32, false),
+            Resolution::R128x64 => (64, true),
+            Resolution::R96x16 => (16, false),
+        };
+
+        let config = DisplayConfig {
+            clock_div: (0x8, 0x0),
+            multiplex: height - 1,
+            display_offset: 0,
+            start_line: 0,
+            charge_pump: true,
+            flush_mode: FlushMode::Horizontal,
+            column_offset: controller.column_offset(),
+            segment_remap: true,
+            reverse_com_dir: true,
+            com_pins: (com_alt, false),
+            contrast: 0x8F,
+            pre_charge: (0x1, 0xF),
+            vcomh: VcomhLevel::Auto,
+            invert: false,
+        };
+
+        match controller {
+            Controller::Ssd1306 => config,
+            Controller::Sh1106 => config.flush_mode(FlushMode::Page),
+            Controller::Ssd1309 => config
+                .clock_div(0xA, 0x0)
+                .contrast(0x6F)
+                .pre_charge(0x3, 0xD)
+                .vcomh(VcomhLevel::V077),
+            Controller::Ssd1305 => config
+                .clock_div(0xF, 0x0)
+                .contrast(0x80)
+                .pre_charge(0x2, 0xD)
+                .vcomh(VcomhLevel::V077),
+        }
+    }
+
+    /// Oscillator frequency and divide ratio - 1, each from 0-15
+    pub fn clock_div(mut self, fosc: u8, div: u8) -> DisplayConfig {
+        self.clock_div = (fosc, div);
+        self
+    }
+
+    /// Multiplex ratio (MUX-1) from 15-63
+    pub fn multiplex(mut self, ratio: u8) -> DisplayConfig {
+        self.multiplex = ratio;
+        self
+    }
+
+    /// Vertical shift from 0-63
+    pub fn display_offset(mut self, offset: u8) -> DisplayConfig {
+        self.display_offset = offset;
+        self
+    }
+
+    /// Display start line from 0-63
+    pub fn start_line(mut self, line: u8) -> DisplayConfig {
+        self.start_line = line;
+        self
+    }
+
+    /// Enable the charge pump, or the DC-DC converter on the SH1106.
+    /// Ignored by controllers powered from external VCC.
+    pub fn charge_pump(mut self, charge_pump: bool) -> DisplayConfig {
+        self.charge_pump = charge_pump;
+        self
+    }
+
+    /// Addressing mode used to write the buffer to the display
+    pub fn flush_mode(mut self, mode: FlushMode) -> DisplayConfig {
+        self.flush_mode = mode;
+        self
+    }
+
+    /// RAM column shown in the leftmost display column
+    pub fn column_offset(mut self, offset: u8) -> DisplayConfig {
+        self.column_offset = offset;
+        self
+    }
+
+    /// Reverse columns from 127-0
+    pub fn segment_remap(mut self, remap: bool) -> DisplayConfig {
+        self.segment_remap = remap;
+        self
+    }
+
+    /// Scan from COM[n-1] to COM0
+    pub fn reverse_com_dir(mut self, rev: bool) -> DisplayConfig {
+        self.reverse_com_dir = rev;
+        self
+    }
+
+    /// Alternative (true) or sequential (false) COM pin configuration
+    /// and left/right remap
+    pub fn com_pins(mut self, alt: bool, lr: bool) -> DisplayConfig {
+        self.com_pins = (alt, lr);
+        self
+    }
+
+    /// Contrast
+    pub fn contrast(mut self, contrast: u8) -> DisplayConfig {
+        self.contrast = contrast;
+        self
+    }
+
+    /// Phase 1 and 2 of precharge period, each from 1-15
+    pub fn pre_charge(mut self, phase1: u8, phase2: u8) -> DisplayConfig {
+        self.pre_charge = (phase1, phase2);
+        self
+    }
+
+    /// Vcomh deselect level
+    pub fn vcomh(mut self, level: VcomhLevel) -> DisplayConfig {
+        self.vcomh = level;
+        self
+    }
+
+    /// Invert display
+    pub fn invert(mut self, invert: bool) -> DisplayConfig {
+        self.invert = invert;
+        self
+    }
+}
@@ -0,0 +1,71 @@
+use cmd::Command;
+
+/// Display controller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Controller {
+    /// SSD1306
+    Ssd1306,
+    /// SH1106, with 132 column RAM and page addressing only
+    Sh1106,
+    /// SSD1309, powered from external VCC
+    Ssd1309,
+    /// SSD1305, with 132 column RAM, powered from external VCC
+    Ssd1305,
+}
+
+impl Controller {
+    /// Number of columns in display RAM
+    pub fn columns(&self) -> u8 {
+        match *self {
+            Controller::Ssd1306 | Controller::Ssd1309 => 128,
+            Controller::Sh1106 | Controller::Ssd1305 => 132,
+        }
+    }
+
+    /// Default RAM column shown in the leftmost display column
+    pub fn column_offset(&self) -> u8 {
+        match *self {
+            Controller::Ssd1306 | Controller::Ssd1309 => 0,
+            Controller::Sh1106 => 2,
+            Controller::Ssd1305 => 4,
+        }
+    }
+
+    /// Whether the controller understands a command
+    pub fn supports(&self, cmd: &Command) -> bool {
+        match *self {
+            Controller::Ssd1306 => !matches!(*cmd, Command::DcDc(_) | Command::ExternalVcc),
+            Controller::Sh1106 => !matches!(
+                *cmd,
+                Command::HScrollSetup(..)
+                | Command::VHScrollSetup(..)
+                | Command::ContentScroll(..)
+                | Command::EnableScroll(_)
+                | Command::VScrollArea(..)
+                | Command::AddressMode(_)
+                | Command::ColumnAddress(..)
+                | Command::PageAddress(..)
+                | Command::ChargePump(_)
+                | Command::FadeOutBlink(..)
+                | Command::ZoomIn(_)
+                | Command::ExternalVcc
+            ),
+            Controller::Ssd1309 => !matches!(
+                *cmd,
+                Command::ChargePump(_)
+                    | Command::FadeOutBlink(..)
+                    | Command::ZoomIn(_)
+                    | Command::DcDc(_)
+                    | Command::ExternalVcc
+            ),
+            Controller::Ssd1305 => !matches!(
+                *cmd,
+                Command::ContentScroll(..)
+                    | Command::ChargePump(_)
+                    | Command::FadeOutBlink(..)
+                    | Command::ZoomIn(_)
+                    | Command::DcDc(_)
+            ),
+        }
+    }
+}
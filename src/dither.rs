@@ -0,0 +1,164 @@
+use canvas::{Canvas, Color};
+use cmd::OutOfRange;
+use fill::BAYER;
+
+/// How gray levels are turned into on and off pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Method {
+    /// Floyd–Steinberg error diffusion, keeping one row of error ahead
+    FloydSteinberg,
+    /// Atkinson error diffusion, keeping two rows of error ahead. Gives more
+    /// contrast than Floyd–Steinberg at the cost of detail in highlights and shadows
+    Atkinson,
+    /// Ordered dither with the 8x8 Bayer matrix, needs no error buffer
+    Bayer,
+}
+
+impl Method {
+    /// Rows of error kept, including the current row
+    fn rows(self) -> usize {
+        match self {
+            Method::FloydSteinberg => 2,
+            Method::Atkinson => 3,
+            Method::Bayer => 0,
+        }
+    }
+}
+
+/// A row of source pixels
+#[derive(Clone, Copy, Debug)]
+pub enum Row<'a> {
+    /// 8-bit grayscale, 0 is black
+    Gray(&'a [u8]),
+    /// RGB565 color
+    Rgb565(&'a [u16]),
+}
+
+impl<'a> Row<'a> {
+    fn len(&self) -> usize {
+        match *self {
+            Row::Gray(p) => p.len(),
+            Row::Rgb565(p) => p.len(),
+        }
+    }
+
+    /// Brightness of pixel `x`, from 0 to 255
+    fn luma(&self, x: usize) -> i16 {
+        match *self {
+            Row::Gray(p) => p[x] as i16,
+            Row::Rgb565(p) => {
+                let c = p[x] as u32;
+                let r = (c >> 11) * 255 / 31;
+                let g = (c >> 5 & 0x3F) * 255 / 63;
+                let b = (c & 0x1F) * 255 / 31;
+                ((r * 77 + g * 150 + b * 29) >> 8) as i16
+            }
+        }
+    }
+}
+
+/// Column padding in error rows, so diffusion never needs bounds checks
+const PAD_LEFT: usize = 1;
+const PAD: usize = 3;
+
+/// Dithers an image row by row onto a canvas, so the image never needs to be
+/// in memory whole. Pixels are set on or off, all of them drawn.
+pub struct Dither<'a> {
+    method: Method,
+    width: u16,
+    x: i32,
+    y: i32,
+    /// Rows drawn so far
+    row: usize,
+    /// Error rows, used as a ring starting at the current row
+    errors: &'a mut [i16],
+}
+
+impl<'a> Dither<'a> {
+    /// Length of the error buffer needed to dither `width` pixel wide images
+    pub fn buffer_len(method: Method, width: u16) -> usize {
+        method.rows() * (width as usize + PAD)
+    }
+
+    /// Dither an image `width` pixels wide with its top left corner at `x`, `y`.
+    /// `errors` needs at least `buffer_len` entries.
+    pub fn new(
+        method: Method,
+        x: i32,
+        y: i32,
+        width: u16,
+        errors: &'a mut [i16],
+    ) -> Result<Dither<'a>, OutOfRange> {
+        let len = Dither::buffer_len(method, width);
+        if errors.len() < len {
+            return Err(OutOfRange);
+        }
+        let errors = &mut errors[..len];
+        for e in errors.iter_mut() {
+            *e = 0;
+        }
+        Ok(Dither {
+            method,
+            width,
+            x,
+            y,
+            row: 0,
+            errors,
+        })
+    }
+
+    /// Draw the next row of the image. Pixels beyond the width are ignored.
+    pub fn draw_row<C: Canvas>(&mut self, c: &mut C, pixels: Row) {
+        let y = self.y + self.row as i32;
+        let width = (self.width as usize).min(pixels.len());
+        let stride = self.width as usize + PAD;
+        let rows = self.method.rows();
+
+        // Start of error row `n` below the current one
+        let start = |n: usize| (self.row + n) % rows.max(1) * stride + PAD_LEFT;
+        let (cur, next, after) = (start(0), start(1), start(2));
+
+        for x in 0..width {
+            let px = self.x + x as i32;
+            let value = pixels.luma(x);
+            let on = match self.method {
+                Method::Bayer => {
+                    let level = (value as u8 as u16 * 64 + 127) / 255;
+                    (BAYER[(y & 7) as usize][(px & 7) as usize] as u16) < level
+                }
+                Method::FloydSteinberg => {
+                    let value = value + self.errors[cur + x];
+                    let on = value >= 128;
+                    let e = value - if on { 255 } else { 0 };
+                    self.errors[cur + x + 1] += e * 7 / 16;
+                    self.errors[next + x - 1] += e * 3 / 16;
+                    self.errors[next + x] += e * 5 / 16;
+                    self.errors[next + x + 1] += e / 16;
+                    on
+                }
+                Method::Atkinson => {
+                    let value = value + self.errors[cur + x];
+                    let on = value >= 128;
+                    let e = value - if on { 255 } else { 0 };
+                    let e = (e + 4 * e.signum()) / 8;
+                    self.errors[cur + x + 1] += e;
+                    self.errors[cur + x + 2] += e;
+                    self.errors[next + x - 1] += e;
+                    self.errors[next + x] += e;
+                    self.errors[next + x + 1] += e;
+                    self.errors[after + x] += e;
+                    on
+                }
+            };
+            c.set_pixel(px, y, if on { Color::On } else { Color::Off });
+        }
+
+        // The current row becomes the furthest row ahead
+        if rows > 0 {
+            for e in self.errors[cur - PAD_LEFT..cur - PAD_LEFT + stride].iter_mut() {
+                *e = 0;
+            }
+        }
+        self.row += 1;
+    }
+}
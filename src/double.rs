@@ -0,0 +1,113 @@
+use core::ops::{Deref, DerefMut};
+
+use hal::blocking::i2c;
+
+use prelude::Write;
+use {Error, Ssd1306, BUF_SIZE};
+
+/// Unchanged columns between two changed spans of a page before they are
+/// sent separately. Each span costs several command bytes to address.
+const SPAN_GAP: usize = 8;
+
+/// Double buffered display. Drawing goes to the back buffer of the wrapped
+/// [`Ssd1306`](../struct.Ssd1306.html) and is presented with
+/// [`swap`](#method.swap), which compares it with the front buffer and only
+/// sends the columns that differ.
+pub struct DoubleBuffered<I2C> {
+    disp: Ssd1306<I2C>,
+    front: [u8; BUF_SIZE],
+}
+
+impl<I2C> DoubleBuffered<I2C>
+where
+    I2C: i2c::Write,
+{
+    /// Add a front buffer to a display. The first swap sends the whole frame.
+    pub fn new(mut disp: Ssd1306<I2C>) -> DoubleBuffered<I2C> {
+        disp.unsynced = true;
+        DoubleBuffered {
+            disp,
+            front: [0; BUF_SIZE],
+        }
+    }
+
+    /// Release the display
+    pub fn free(self) -> Ssd1306<I2C> {
+        self.disp
+    }
+
+    /// Present the back buffer, sending only the spans that differ from the front buffer
+    pub fn swap(&mut self) -> Result<(), Error<I2C::Error>> {
+        let disp = &mut self.disp;
+        if disp.dirty & disp.page_mask() == 0 && !disp.unsynced {
+            return disp.apply_scroll_offset();
+        }
+        if disp.dirty != 0 {
+            disp.burn_in_activity()?;
+        }
+
+        // Scrolling and burn-in shifting need whole pages rewritten, and so
+        // does display RAM changed behind the front buffer's back
+        if disp.unsynced || disp.scroll.is_some() || disp.burn_in.is_some() {
+            disp.dirty = 0xFF;
+            disp.flush()?;
+            self.front.copy_from_slice(&disp.buf);
+            disp.unsynced = false;
+            return Ok(());
+        }
+
+        let dirty = disp.dirty & disp.page_mask();
+        let width = disp.width as usize;
+        for page in 0..8 {
+            if dirty & (1 << page) == 0 {
+                continue;
+            }
+            let row = page as usize * 128;
+
+            let mut col = 0;
+            while col < width {
+                if disp.buf[row + col] == self.front[row + col] {
+                    col += 1;
+                    continue;
+                }
+
+                let start = col;
+                let mut end = col;
+                while col < width && col - end <= SPAN_GAP {
+                    if disp.buf[row + col] != self.front[row + col] {
+                        end = col;
+                    }
+                    col += 1;
+                }
+
+                disp.set_window(page, page, start as u8, end as u8)?;
+                disp.i2c
+                    .write_data(disp.addr, &disp.buf[row + start..row + end + 1])
+                    .map_err(Error::Comm)?;
+            }
+
+            self.front[row..row + width].copy_from_slice(&disp.buf[row..row + width]);
+        }
+        disp.dirty = 0;
+        disp.apply_scroll_offset()
+    }
+
+    /// Same as [`swap`](#method.swap), keeping the front buffer in step with the display
+    pub fn draw(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.swap()
+    }
+}
+
+impl<I2C> Deref for DoubleBuffered<I2C> {
+    type Target = Ssd1306<I2C>;
+
+    fn deref(&self) -> &Ssd1306<I2C> {
+        &self.disp
+    }
+}
+
+impl<I2C> DerefMut for DoubleBuffered<I2C> {
+    fn deref_mut(&mut self) -> &mut Ssd1306<I2C> {
+        &mut self.disp
+    }
+}
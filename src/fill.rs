@@ -0,0 +1,308 @@
+use core::cmp::{max, min};
+
+use canvas::{Canvas, Color};
+use primitives::{ellipse_spans, hline};
+use shapes::polygon_spans;
+
+/// Seeds the flood fill can hold at once
+const STACK_SIZE: usize = 64;
+
+/// Neighbor offsets, clockwise from up
+const DIRS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// 8x8 ordered dither thresholds, indexed by row then column
+pub(crate) const BAYER: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// 8x8 stipple repeated across a fill. Like a page of display RAM, each byte
+/// is a column with the top row in the lowest bit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pattern(pub [u8; 8]);
+
+impl Pattern {
+    /// Every pixel
+    pub const SOLID: Pattern = Pattern([0xFF; 8]);
+    /// Alternating pixels
+    pub const CHECKERBOARD: Pattern = Pattern([0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA]);
+    /// Diagonal lines
+    pub const HATCH: Pattern = Pattern([0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80]);
+    /// Crossing diagonal lines
+    pub const CROSS_HATCH: Pattern = Pattern([0x81, 0x42, 0x24, 0x18, 0x18, 0x24, 0x42, 0x81]);
+    /// Horizontal lines every four rows
+    pub const HORIZONTAL: Pattern = Pattern([0x11; 8]);
+    /// Vertical lines every four columns
+    pub const VERTICAL: Pattern = Pattern([0xFF, 0, 0, 0, 0xFF, 0, 0, 0]);
+
+    /// Ordered dither with `level` of 64 pixels set, from 0 to 64
+    pub fn dither(level: u8) -> Pattern {
+        let mut cols = [0; 8];
+        for (x, col) in cols.iter_mut().enumerate() {
+            for (y, row) in BAYER.iter().enumerate() {
+                if row[x] < level {
+                    *col |= 1 << y;
+                }
+            }
+        }
+        Pattern(cols)
+    }
+
+    /// Pattern bits for the rows of a page at column `x`
+    fn column(&self, x: i32) -> u8 {
+        self.0[(x & 7) as usize]
+    }
+}
+
+/// Fill the area of pixels connected to `x`, `y` that have its value.
+/// Parts of the area beyond what the seed stack holds are filled by walking
+/// their outline, which is slower but needs no memory.
+pub fn flood_fill<C: Canvas>(c: &mut C, x: i32, y: i32, color: Color) {
+    let (width, height) = c.size();
+    let (width, height) = (width as i32, height as i32);
+    if x < 0 || y < 0 || x >= width || y >= height {
+        return;
+    }
+    let target = c.get_pixel(x, y);
+    let fill = match color {
+        Color::On => true,
+        Color::Off => false,
+        Color::Invert => !target,
+    };
+    if fill == target {
+        return;
+    }
+
+    let mut stack = [(0i16, 0i16); STACK_SIZE];
+    stack[0] = (x as i16, y as i16);
+    let mut len = 1;
+
+    while len > 0 {
+        len -= 1;
+        let (x, y) = (stack[len].0 as i32, stack[len].1 as i32);
+        if c.get_pixel(x, y) != target {
+            continue;
+        }
+
+        let mut left = x;
+        while left > 0 && c.get_pixel(left - 1, y) == target {
+            left -= 1;
+        }
+        let mut right = x;
+        while right + 1 < width && c.get_pixel(right + 1, y) == target {
+            right += 1;
+        }
+        hline(c, left, right, y, color);
+
+        // Seed each run of the area in the rows above and below
+        for &ny in &[y - 1, y + 1] {
+            if ny < 0 || ny >= height {
+                continue;
+            }
+            let mut run = false;
+            for nx in left..right + 1 {
+                let inside = c.get_pixel(nx, ny) == target;
+                if inside && !run {
+                    if len < STACK_SIZE {
+                        stack[len] = (nx as i16, ny as i16);
+                        len += 1;
+                    } else {
+                        fill_walking(c, nx, ny, target, color);
+                    }
+                }
+                run = inside;
+            }
+        }
+    }
+}
+
+/// Fill the area connected to `x`, `y` without a stack. Pixels are only
+/// painted when the rest of the area stays connected without them, so the
+/// area can always be walked to its last pixel.
+fn fill_walking<C: Canvas>(c: &mut C, x: i32, y: i32, target: bool, color: Color) {
+    let mut cur = (x, y);
+    // Direction of the unpainted pixel the walk came from
+    let mut from: Option<usize> = None;
+    loop {
+        let mut next = 0u8;
+        for (d, &(dx, dy)) in DIRS.iter().enumerate() {
+            if inside(c, (cur.0 + dx, cur.1 + dy), cur, target) {
+                next |= 1 << d;
+            }
+        }
+        if next == 0 {
+            c.set_pixel(cur.0, cur.1, color);
+            return;
+        }
+
+        let start = match from {
+            Some(d) if next & (1 << d) != 0 => d,
+            _ => next.trailing_zeros() as usize,
+        };
+        let reached = if local_groups(c, cur, next, target) == 1 {
+            next
+        } else {
+            outline(c, cur, start, target) & next
+        };
+
+        // If the outline reaches every neighbor, the area stays connected
+        // without `cur`. Otherwise walk into the part it cut off.
+        let d = if reached == next {
+            c.set_pixel(cur.0, cur.1, color);
+            from = None;
+            start
+        } else {
+            let d = (next & !reached).trailing_zeros() as usize;
+            from = Some((d + 2) & 3);
+            d
+        };
+        cur = (cur.0 + DIRS[d].0, cur.1 + DIRS[d].1);
+    }
+}
+
+/// Whether `p` is an unpainted pixel of the area, taking `cur` as already painted
+fn inside<C: Canvas>(c: &C, p: (i32, i32), cur: (i32, i32), target: bool) -> bool {
+    let (width, height) = c.size();
+    p != cur && p.0 >= 0 && p.1 >= 0 && p.0 < width as i32 && p.1 < height as i32 && c.get_pixel(p.0, p.1) == target
+}
+
+/// Number of groups the neighbors set in `next` form around `cur`,
+/// joined through the diagonal pixels between them
+fn local_groups<C: Canvas>(c: &C, cur: (i32, i32), next: u8, target: bool) -> u32 {
+    let count = next.count_ones();
+    let mut joins = 0;
+    for (d, &(dx, dy)) in DIRS.iter().enumerate() {
+        let e = (d + 1) & 3;
+        let corner = (cur.0 + dx + DIRS[e].0, cur.1 + dy + DIRS[e].1);
+        if next & (1 << d) != 0 && next & (1 << e) != 0 && inside(c, corner, cur, target) {
+            joins += 1;
+        }
+    }
+    if joins == 4 {
+        1
+    } else {
+        count - joins
+    }
+}
+
+/// Follow the outline of the wall around `cur`, starting at its neighbor in
+/// direction `start` with the wall on the left. Returns the directions of the
+/// neighbors of `cur` passed on the way, which are the ones still connected
+/// to the start without `cur`.
+fn outline<C: Canvas>(c: &C, cur: (i32, i32), start: usize, target: bool) -> u8 {
+    let first = ((cur.0 + DIRS[start].0, cur.1 + DIRS[start].1), (start + 3) & 3);
+    let (mut p, mut dir) = first;
+    let mut reached = 0;
+    loop {
+        let left = (dir + 3) & 3;
+        if (p.0 + DIRS[left].0, p.1 + DIRS[left].1) == cur {
+            reached |= 1 << ((left + 2) & 3);
+        }
+
+        let front = (p.0 + DIRS[dir].0, p.1 + DIRS[dir].1);
+        let corner = (front.0 + DIRS[left].0, front.1 + DIRS[left].1);
+        if !inside(c, front, cur, target) {
+            dir = (dir + 1) & 3;
+        } else if !inside(c, corner, cur, target) {
+            p = front;
+        } else {
+            p = corner;
+            dir = left;
+        }
+        if (p, dir) == first {
+            return reached;
+        }
+    }
+}
+
+/// Fill a rectangle with a pattern, a page byte at a time
+pub fn fill_rect_pattern<C: Canvas>(c: &mut C, x: i32, y: i32, w: i32, h: i32, pattern: &Pattern, color: Color) {
+    let (width, height) = c.size();
+    let (x0, x1) = (max(x, 0), min(x + w, width as i32) - 1);
+    let (y0, y1) = (max(y, 0), min(y + h, height as i32) - 1);
+
+    let mut top = y0;
+    while top <= y1 {
+        let page = top >> 3;
+        let bottom = min(y1, page * 8 + 7);
+        let mask = (0xFF << (top & 7)) & (0xFF >> (7 - (bottom & 7)));
+        for col in x0..x1 + 1 {
+            let bits = mask & pattern.column(col);
+            if bits != 0 {
+                c.apply_mask(col as u16, page as u16, bits, color);
+            }
+        }
+        top = bottom + 1;
+    }
+}
+
+/// Fill a circle with a pattern
+pub fn fill_circle_pattern<C: Canvas>(c: &mut C, cx: i32, cy: i32, r: i32, pattern: &Pattern, color: Color) {
+    ellipse_spans(cx, cy, r, r, |x0, x1, y| pattern_span(c, x0, x1, y, pattern, color));
+}
+
+/// Fill a polygon with a pattern using the even-odd rule
+pub fn fill_polygon_pattern<C: Canvas>(c: &mut C, points: &[(i32, i32)], pattern: &Pattern, color: Color) {
+    polygon_spans(points, |x0, x1, y| pattern_span(c, x0, x1, y, pattern, color));
+}
+
+/// Apply a pattern to columns `x0` to `x1` of row `y`
+fn pattern_span<C: Canvas>(c: &mut C, x0: i32, x1: i32, y: i32, pattern: &Pattern, color: Color) {
+    let (width, height) = c.size();
+    if y < 0 || y >= height as i32 {
+        return;
+    }
+    let row = 1 << (y & 7);
+    for x in max(x0, 0)..min(x1, width as i32 - 1) + 1 {
+        let bits = row & pattern.column(x);
+        if bits != 0 {
+            c.apply_mask(x as u16, (y >> 3) as u16, bits, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use canvas::VirtualCanvas;
+    use primitives::{fill_rect, rect};
+
+    #[test]
+    fn stipple_overflows_stack() {
+        let mut buf = [0; 1024];
+        let mut c = VirtualCanvas::new(&mut buf, 128, 64).unwrap();
+        for y in (3..64).step_by(4) {
+            for x in (0..128).step_by(2) {
+                c.set_pixel(x, y, Color::On);
+            }
+        }
+        flood_fill(&mut c, 1, 0, Color::On);
+        assert!(buf.iter().all(|&b| b == 0xFF));
+    }
+
+    #[test]
+    fn overflow_stays_in_area() {
+        let mut buf = [0; 1024];
+        let mut c = VirtualCanvas::new(&mut buf, 128, 64).unwrap();
+        for y in (1..64).step_by(2) {
+            for x in (y % 4 / 2..128).step_by(2) {
+                c.set_pixel(x, y, Color::On);
+            }
+        }
+        rect(&mut c, 40, 20, 30, 20, Color::On);
+        fill_rect(&mut c, 41, 21, 28, 18, Color::Off);
+        flood_fill(&mut c, 0, 0, Color::On);
+        for y in 21..39 {
+            for x in 41..69 {
+                assert!(!c.get_pixel(x, y));
+            }
+        }
+        assert!(c.get_pixel(0, 1) && c.get_pixel(127, 63));
+    }
+}
@@ -0,0 +1,143 @@
+use canvas::{Canvas, Color};
+
+/// Bitmap font. Each glyph is stored like display RAM, as a byte per column
+/// for every started page of 8 rows, with the top row in the lowest bit.
+/// Proportional fonts store glyphs left aligned in `width` columns.
+#[derive(Clone, Copy)]
+pub struct Font {
+    /// Glyph width in pixels
+    pub width: u8,
+    /// Glyph height in pixels
+    pub height: u8,
+    /// Runs of consecutive characters the font has glyphs for, in code point
+    /// order, with the position of the glyph of the first character
+    pub ranges: &'static [(char, char, u16)],
+    /// Glyph data
+    pub data: &'static [u8],
+    /// Advance of each glyph including spacing, empty for fixed width fonts
+    pub widths: &'static [u8],
+    /// Advance adjustments for pairs of characters, sorted by pair
+    pub kerning: &'static [(char, char, i8)],
+    /// Character drawn in place of those the font has no glyph for
+    pub replacement: Option<char>,
+}
+
+impl Font {
+    /// Pages each glyph spans
+    pub fn pages(&self) -> usize {
+        (self.height as usize + 7) >> 3
+    }
+
+    /// Same font drawing `replacement` for missing characters, or leaving them blank
+    pub fn with_replacement(self, replacement: Option<char>) -> Font {
+        Font { replacement, ..self }
+    }
+
+    /// Data of the glyph for `c`, or of the replacement if the font has none
+    pub fn glyph(&self, c: char) -> Option<&'static [u8]> {
+        let size = self.width as usize * self.pages();
+        let index = self.index(c)?;
+        self.data.get(index * size..(index + 1) * size)
+    }
+
+    /// Position of the glyph for `c` in the font, falling back to the replacement
+    fn index(&self, c: char) -> Option<usize> {
+        self.lookup(c).or_else(|| self.lookup(self.replacement?))
+    }
+
+    /// Position of the glyph for `c` in the font
+    fn lookup(&self, c: char) -> Option<usize> {
+        let i = match self.ranges.binary_search_by(|&(first, _, _)| first.cmp(&c)) {
+            Ok(i) => i,
+            Err(0) => return None,
+            Err(i) => i - 1,
+        };
+        let (first, last, base) = self.ranges[i];
+        if c <= last {
+            Some(base as usize + (c as usize - first as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Columns from the start of a character to the next
+    pub fn advance(&self, c: char) -> i32 {
+        match self.index(c).and_then(|i| self.widths.get(i)) {
+            Some(&w) => w as i32,
+            None => self.width as i32,
+        }
+    }
+
+    /// Adjustment of the advance from `a` to `b`
+    pub fn kern(&self, a: char, b: char) -> i32 {
+        match self.kerning.binary_search_by(|&(x, y, _)| (x, y).cmp(&(a, b))) {
+            Ok(i) => self.kerning[i].2 as i32,
+            Err(_) => 0,
+        }
+    }
+
+    /// Width of text in pixels
+    pub fn measure(&self, text: &str) -> i32 {
+        let mut width = 0;
+        let mut prev = None;
+        for ch in text.chars() {
+            if let Some(p) = prev {
+                width += self.kern(p, ch);
+            }
+            width += self.advance(ch);
+            prev = Some(ch);
+        }
+        width
+    }
+}
+
+/// Draw text with its top left corner at `x`, `y`, leaving the background
+/// untouched. Characters the font has no glyph for are drawn as its
+/// replacement character, or left blank if it has none.
+/// Returns the column after the text.
+pub fn draw_text<C: Canvas>(c: &mut C, x: i32, y: i32, text: &str, font: &Font, color: Color) -> i32 {
+    let mut x = x;
+    let mut prev = None;
+    for ch in text.chars() {
+        if let Some(p) = prev {
+            x += font.kern(p, ch);
+        }
+        if let Some(glyph) = font.glyph(ch) {
+            draw_glyph(c, x, y, glyph, font.width, font.pages(), color);
+        }
+        x += font.advance(ch);
+        prev = Some(ch);
+    }
+    x
+}
+
+/// Draw a glyph of `pages` pages of `width` columns
+pub(crate) fn draw_glyph<C: Canvas>(c: &mut C, x: i32, y: i32, glyph: &[u8], width: u8, pages: usize, color: Color) {
+    let (cw, ch) = c.size();
+    let (cw, cpages) = (cw as i32, (ch as i32 + 7) >> 3);
+    let (top, shift) = (y >> 3, y & 7);
+
+    for (p, cols) in glyph.chunks(width as usize).take(pages).enumerate() {
+        let page = top + p as i32;
+        for (col, &b) in cols.iter().enumerate() {
+            let col = x + col as i32;
+            if b == 0 || col < 0 || col >= cw {
+                continue;
+            }
+            // Page aligned glyphs copy their columns as is
+            if shift == 0 {
+                if page >= 0 && page < cpages {
+                    c.apply_mask(col as u16, page as u16, b, color);
+                }
+                continue;
+            }
+            let (lo, hi) = (b << shift, b >> (8 - shift));
+            if lo != 0 && page >= 0 && page < cpages {
+                c.apply_mask(col as u16, page as u16, lo, color);
+            }
+            if hi != 0 && page + 1 >= 0 && page + 1 < cpages {
+                c.apply_mask(col as u16, (page + 1) as u16, hi, color);
+            }
+        }
+    }
+}
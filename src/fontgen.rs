@@ -0,0 +1,346 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::string::String;
+use std::vec::Vec;
+
+/// Line of a BDF font that could not be parsed, counting from 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParseError(pub usize);
+
+/// Glyph as found in a BDF font
+struct Glyph {
+    advance: i32,
+    bbx: (i32, i32, i32, i32),
+    /// Bitmap rows, left pixel in the highest bit of the first byte
+    rows: Vec<Vec<u8>>,
+}
+
+impl Glyph {
+    fn pixel(&self, x: i32, y: i32) -> bool {
+        let row = &self.rows[y as usize];
+        row.get(x as usize / 8).is_some_and(|b| b & (0x80 >> (x % 8)) != 0)
+    }
+}
+
+/// Compiler of BDF fonts to `Font` statics, for build scripts.
+/// Needs the `fontgen` feature.
+pub struct FontGen {
+    name: String,
+    glyphs: BTreeMap<char, Glyph>,
+    ascent: i32,
+    descent: i32,
+    x_off: i32,
+    subset: Vec<(char, char)>,
+    spacing: Option<u8>,
+    replacement: Option<char>,
+}
+
+impl FontGen {
+    /// Parse a BDF font
+    pub fn new(bdf: &str) -> Result<FontGen, ParseError> {
+        let mut glyphs = BTreeMap::new();
+        let mut bbox = None;
+        let (mut ascent, mut descent) = (None, None);
+
+        let mut lines = bdf.lines().enumerate().map(|(i, l)| (i + 1, l.trim()));
+        while let Some((n, line)) = lines.next() {
+            let mut words = line.split_whitespace();
+            match words.next() {
+                Some("FONTBOUNDINGBOX") => bbox = Some(numbers(words, n)?),
+                Some("FONT_ASCENT") => ascent = Some(number(words.next(), n)?),
+                Some("FONT_DESCENT") => descent = Some(number(words.next(), n)?),
+                Some("STARTCHAR") => {
+                    let (mut code, mut advance, mut bbx) = (None, 0, None);
+                    let mut rows = Vec::new();
+                    let end = loop {
+                        let (n, line) = lines.next().ok_or(ParseError(n))?;
+                        let mut words = line.split_whitespace();
+                        match words.next() {
+                            Some("ENCODING") => code = Some(number(words.next(), n)?),
+                            Some("DWIDTH") => advance = number(words.next(), n)?,
+                            Some("BBX") => bbx = Some(numbers(words, n)?),
+                            Some("BITMAP") => {
+                                let (_, h, _, _) = bbx.ok_or(ParseError(n))?;
+                                for _ in 0..h {
+                                    let (n, line) = lines.next().ok_or(ParseError(n))?;
+                                    rows.push(hex(line, n)?);
+                                }
+                            }
+                            Some("ENDCHAR") => break n,
+                            _ => (),
+                        }
+                    };
+
+                    // Glyphs without a Unicode encoding are left out
+                    let c = code.and_then(|c: i32| ::core::char::from_u32(c as u32));
+                    if let (Some(c), Some(bbx)) = (c, bbx) {
+                        if rows.len() as i32 != bbx.1 {
+                            return Err(ParseError(end));
+                        }
+                        glyphs.insert(c, Glyph { advance, bbx, rows });
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        // A missing bounding box is reported at the last line
+        let (_, h, x, y) = bbox.ok_or(ParseError(bdf.lines().count().max(1)))?;
+        Ok(FontGen {
+            name: String::from("FONT"),
+            glyphs,
+            ascent: ascent.unwrap_or(h + y),
+            descent: descent.unwrap_or(-y),
+            x_off: x,
+            subset: Vec::new(),
+            spacing: None,
+            replacement: None,
+        })
+    }
+
+    /// Name of the generated static, `FONT` by default
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = String::from(name);
+        self
+    }
+
+    /// Add characters to the subset
+    pub fn chars(mut self, chars: &str) -> Self {
+        for c in chars.chars() {
+            self.subset.push((c, c));
+        }
+        self
+    }
+
+    /// Add a range of characters to the subset
+    pub fn range(mut self, first: char, last: char) -> Self {
+        self.subset.push((first, last));
+        self
+    }
+
+    /// Trim glyphs to the columns they use and space them `spacing` columns apart
+    pub fn proportional(mut self, spacing: u8) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Draw `c` for characters the font has no glyph for instead of leaving them blank
+    pub fn replacement(mut self, c: char) -> Self {
+        self.replacement = Some(c);
+        self
+    }
+
+    /// Rust source of a static `Font` with the glyphs of the subset, or all
+    /// glyphs of the font when no subset was chosen. Characters the font has
+    /// no glyph for are left out. Glyphs are cut off below 64 rows.
+    pub fn generate(&self) -> String {
+        let height = (self.ascent + self.descent).clamp(0, 64) as usize;
+        let pages = (height + 7) >> 3;
+
+        let chars: Vec<char> = self
+            .glyphs
+            .keys()
+            .cloned()
+            .filter(|&c| self.subset.is_empty() || self.subset.iter().any(|&(a, b)| a <= c && c <= b))
+            .collect();
+        let glyphs: Vec<(Vec<u64>, i32)> = chars.iter().map(|c| self.render(&self.glyphs[c], height)).collect();
+
+        let width = glyphs.iter().map(|g| g.0.len()).max().unwrap_or(0);
+        let fixed = glyphs.iter().all(|g| g.1 == width as i32);
+
+        let mut ranges: Vec<(char, char, usize)> = Vec::new();
+        for (i, &c) in chars.iter().enumerate() {
+            match ranges.last_mut() {
+                Some(r) if r.1 as u32 + 1 == c as u32 => r.1 = c,
+                _ => ranges.push((c, c, i)),
+            }
+        }
+
+        let mut out = String::new();
+        let _ = writeln!(out, "/// Generated from a BDF font");
+        let _ = writeln!(out, "pub static {}: ::ssd1306::font::Font = ::ssd1306::font::Font {{", self.name);
+        let _ = writeln!(out, "    width: {},", width);
+        let _ = writeln!(out, "    height: {},", height);
+        let _ = write!(out, "    ranges: &[");
+        for (i, r) in ranges.iter().enumerate() {
+            let _ = write!(out, "{}({:?}, {:?}, {})", if i == 0 { "" } else { ", " }, r.0, r.1, r.2);
+        }
+        let _ = writeln!(out, "],");
+        let _ = writeln!(out, "    data: &[");
+        for (c, (cols, _)) in chars.iter().zip(&glyphs) {
+            let _ = write!(out, "        ");
+            for page in 0..pages {
+                for x in 0..width {
+                    let col = cols.get(x).cloned().unwrap_or(0);
+                    let _ = write!(out, "0x{:02X}, ", (col >> (page * 8)) as u8);
+                }
+            }
+            let _ = writeln!(out, "// {:?}", c);
+        }
+        let _ = writeln!(out, "    ],");
+        let _ = write!(out, "    widths: &[");
+        if !fixed {
+            for (i, g) in glyphs.iter().enumerate() {
+                let _ = write!(out, "{}{}", if i == 0 { "" } else { ", " }, g.1.clamp(0, 255));
+            }
+        }
+        let _ = writeln!(out, "],");
+        let _ = writeln!(out, "    kerning: &[],");
+        let _ = writeln!(out, "    replacement: {:?},", self.replacement);
+        let _ = writeln!(out, "}};");
+        out
+    }
+
+    /// Columns of a glyph placed on the baseline, top row in the lowest bit,
+    /// and its advance
+    fn render(&self, glyph: &Glyph, height: usize) -> (Vec<u64>, i32) {
+        let (w, h, gx, gy) = glyph.bbx;
+        let mut cols: Vec<u64> = Vec::new();
+        for y in 0..h {
+            let row = self.ascent - (gy + h) + y;
+            if row < 0 || row >= height as i32 {
+                continue;
+            }
+            for x in 0..w {
+                let col = gx - self.x_off + x;
+                if col >= 0 && glyph.pixel(x, y) {
+                    if cols.len() <= col as usize {
+                        cols.resize(col as usize + 1, 0);
+                    }
+                    cols[col as usize] |= 1 << row;
+                }
+            }
+        }
+
+        match self.spacing {
+            Some(spacing) => {
+                let first = cols.iter().position(|&c| c != 0).unwrap_or(cols.len());
+                cols.drain(..first);
+                let advance = if cols.is_empty() {
+                    glyph.advance
+                } else {
+                    (cols.len() + spacing as usize) as i32
+                };
+                (cols, advance)
+            }
+            None => {
+                let width = cols.len().max(glyph.advance.max(0) as usize);
+                cols.resize(width, 0);
+                (cols, glyph.advance)
+            }
+        }
+    }
+}
+
+/// Parse a decimal number
+fn number(word: Option<&str>, line: usize) -> Result<i32, ParseError> {
+    word.and_then(|w| w.parse().ok()).ok_or(ParseError(line))
+}
+
+/// Parse four decimal numbers
+fn numbers<'a, I>(mut words: I, line: usize) -> Result<(i32, i32, i32, i32), ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    Ok((
+        number(words.next(), line)?,
+        number(words.next(), line)?,
+        number(words.next(), line)?,
+        number(words.next(), line)?,
+    ))
+}
+
+/// Parse a row of hex bitmap bytes
+fn hex(line: &str, n: usize) -> Result<Vec<u8>, ParseError> {
+    let digits = line.as_bytes();
+    let mut row = Vec::new();
+    for pair in digits.chunks(2) {
+        let s = ::core::str::from_utf8(pair).map_err(|_| ParseError(n))?;
+        row.push(u8::from_str_radix(s, 16).map_err(|_| ParseError(n))?);
+    }
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BDF: &str = "STARTFONT 2.1
+FONTBOUNDINGBOX 3 4 0 -1
+STARTPROPERTIES 2
+FONT_ASCENT 3
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 3
+STARTCHAR A
+ENCODING 65
+DWIDTH 3 0
+BBX 2 3 0 0
+BITMAP
+40
+C0
+40
+ENDCHAR
+STARTCHAR B
+ENCODING 66
+DWIDTH 3 0
+BBX 3 1 0 -1
+BITMAP
+E0
+ENDCHAR
+STARTCHAR unmapped
+ENCODING -1
+DWIDTH 3 0
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn round_trip() {
+        let out = FontGen::new(BDF).unwrap().name("TEST").generate();
+        assert!(out.contains("pub static TEST: ::ssd1306::font::Font"));
+        assert!(out.contains("    width: 3,\n    height: 4,\n"));
+        assert!(out.contains("    ranges: &[('A', 'B', 0)],\n"));
+        assert!(out.contains("        0x02, 0x07, 0x00, // 'A'\n        0x08, 0x08, 0x08, // 'B'\n    ],"));
+        assert!(out.contains("    widths: &[],\n"));
+    }
+
+    #[test]
+    fn proportional_widths() {
+        let out = FontGen::new(BDF).unwrap().proportional(1).generate();
+        assert!(out.contains("        0x02, 0x07, 0x00, // 'A'\n"));
+        assert!(out.contains("    widths: &[3, 4],\n"));
+    }
+
+    #[test]
+    fn unencoded_glyphs_skipped() {
+        let font = FontGen::new(BDF).unwrap();
+        assert_eq!(font.glyphs.len(), 2);
+        assert_eq!(font.generate().matches(", // ").count(), 2);
+    }
+
+    #[test]
+    fn subset() {
+        let out = FontGen::new(BDF).unwrap().chars("B").generate();
+        assert!(out.contains("    ranges: &[('B', 'B', 0)],\n"));
+        assert!(!out.contains("'A'"));
+        let out = FontGen::new(BDF).unwrap().range('A', 'Z').generate();
+        assert!(out.contains("    ranges: &[('A', 'B', 0)],\n"));
+    }
+
+    #[test]
+    fn error_lines() {
+        // Third bitmap row of 'A' replaced by ENDCHAR on line 15
+        let truncated = BDF.replacen("40\nENDCHAR", "ENDCHAR", 1);
+        assert_eq!(FontGen::new(&truncated).err(), Some(ParseError(15)));
+        // Font ending in the middle of a bitmap
+        let cut: String = BDF.lines().take(13).map(|l| String::from(l) + "\n").collect();
+        assert_eq!(FontGen::new(&cut).err(), Some(ParseError(12)));
+        let no_bbox = BDF.replacen("FONTBOUNDINGBOX 3 4 0 -1", "COMMENT", 1);
+        assert_eq!(FontGen::new(&no_bbox).err(), Some(ParseError(31)));
+        assert_eq!(FontGen::new("").err(), Some(ParseError(1)));
+    }
+}
@@ -0,0 +1,1197 @@
+use font::Font;
+
+/// 5x7 font, ASCII, part of Latin-1 and Omega
+pub static FONT_5X7: Font = Font {
+    width: 5,
+    height: 7,
+    ranges: &[(' ', '~', 0), ('\u{a0}', '¿', 95), ('Ä', 'Ç', 127), ('Ð', 'Ñ', 131), ('Ö', 'Ø', 133), ('Ü', 'Ü', 136), ('Þ', 'ÿ', 137), ('\u{3a9}', '\u{3a9}', 171), ('\u{2126}', '\u{2126}', 172)],
+    data: &[
+        0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+        0x00, 0x00, 0x2F, 0x00, 0x00, // '!'
+        0x00, 0x07, 0x00, 0x07, 0x00, // '"'
+        0x14, 0x3E, 0x14, 0x3E, 0x14, // '#'
+        0x04, 0x2A, 0x3E, 0x2A, 0x10, // '$'
+        0x13, 0x08, 0x04, 0x32, 0x00, // '%'
+        0x14, 0x2A, 0x14, 0x20, 0x00, // '&'
+        0x00, 0x00, 0x07, 0x00, 0x00, // '\''
+        0x00, 0x1E, 0x21, 0x00, 0x00, // '('
+        0x00, 0x21, 0x1E, 0x00, 0x00, // ')'
+        0x00, 0x2A, 0x1C, 0x2A, 0x00, // '*'
+        0x08, 0x08, 0x3E, 0x08, 0x08, // '+'
+        0x00, 0x40, 0x30, 0x10, 0x00, // ','
+        0x08, 0x08, 0x08, 0x08, 0x00, // '-'
+        0x00, 0x30, 0x30, 0x00, 0x00, // '.'
+        0x10, 0x08, 0x04, 0x02, 0x00, // '/'
+        0x00, 0x1E, 0x21, 0x1E, 0x00, // '0'
+        0x00, 0x22, 0x3F, 0x20, 0x00, // '1'
+        0x22, 0x31, 0x29, 0x26, 0x00, // '2'
+        0x11, 0x25, 0x25, 0x1B, 0x00, // '3'
+        0x0C, 0x0A, 0x3F, 0x08, 0x00, // '4'
+        0x17, 0x25, 0x25, 0x19, 0x00, // '5'
+        0x1E, 0x25, 0x25, 0x18, 0x00, // '6'
+        0x01, 0x31, 0x0D, 0x03, 0x00, // '7'
+        0x1A, 0x25, 0x25, 0x1A, 0x00, // '8'
+        0x06, 0x29, 0x29, 0x1E, 0x00, // '9'
+        0x00, 0x36, 0x36, 0x00, 0x00, // ':'
+        0x40, 0x36, 0x16, 0x00, 0x00, // ';'
+        0x00, 0x08, 0x14, 0x22, 0x00, // '<'
+        0x14, 0x14, 0x14, 0x14, 0x00, // '='
+        0x00, 0x22, 0x14, 0x08, 0x00, // '>'
+        0x00, 0x02, 0x29, 0x06, 0x00, // '?'
+        0x1E, 0x21, 0x2D, 0x0E, 0x00, // '@'
+        0x3E, 0x09, 0x09, 0x3E, 0x00, // 'A'
+        0x3F, 0x25, 0x25, 0x1A, 0x00, // 'B'
+        0x1E, 0x21, 0x21, 0x12, 0x00, // 'C'
+        0x3F, 0x21, 0x21, 0x1E, 0x00, // 'D'
+        0x3F, 0x25, 0x25, 0x21, 0x00, // 'E'
+        0x3F, 0x05, 0x05, 0x01, 0x00, // 'F'
+        0x1E, 0x21, 0x29, 0x3A, 0x00, // 'G'
+        0x3F, 0x04, 0x04, 0x3F, 0x00, // 'H'
+        0x00, 0x21, 0x3F, 0x21, 0x00, // 'I'
+        0x10, 0x20, 0x20, 0x1F, 0x00, // 'J'
+        0x3F, 0x0C, 0x12, 0x21, 0x00, // 'K'
+        0x3F, 0x20, 0x20, 0x20, 0x00, // 'L'
+        0x3F, 0x06, 0x06, 0x3F, 0x00, // 'M'
+        0x3F, 0x06, 0x18, 0x3F, 0x00, // 'N'
+        0x1E, 0x21, 0x21, 0x1E, 0x00, // 'O'
+        0x3F, 0x09, 0x09, 0x06, 0x00, // 'P'
+        0x1E, 0x31, 0x21, 0x5E, 0x00, // 'Q'
+        0x3F, 0x09, 0x19, 0x26, 0x00, // 'R'
+        0x12, 0x25, 0x29, 0x12, 0x00, // 'S'
+        0x00, 0x01, 0x3F, 0x01, 0x00, // 'T'
+        0x1F, 0x20, 0x20, 0x1F, 0x00, // 'U'
+        0x0F, 0x30, 0x30, 0x0F, 0x00, // 'V'
+        0x3F, 0x18, 0x18, 0x3F, 0x00, // 'W'
+        0x33, 0x0C, 0x0C, 0x33, 0x00, // 'X'
+        0x00, 0x07, 0x38, 0x07, 0x00, // 'Y'
+        0x31, 0x29, 0x25, 0x23, 0x00, // 'Z'
+        0x00, 0x3F, 0x21, 0x21, 0x00, // '['
+        0x02, 0x04, 0x08, 0x10, 0x00, // '\\'
+        0x00, 0x21, 0x21, 0x3F, 0x00, // ']'
+        0x00, 0x02, 0x01, 0x02, 0x00, // '^'
+        0x20, 0x20, 0x20, 0x20, 0x00, // '_'
+        0x00, 0x01, 0x02, 0x00, 0x00, // '`'
+        0x18, 0x24, 0x14, 0x3C, 0x00, // 'a'
+        0x3F, 0x24, 0x24, 0x18, 0x00, // 'b'
+        0x18, 0x24, 0x24, 0x00, 0x00, // 'c'
+        0x18, 0x24, 0x24, 0x3F, 0x00, // 'd'
+        0x18, 0x34, 0x2C, 0x08, 0x00, // 'e'
+        0x08, 0x3E, 0x09, 0x02, 0x00, // 'f'
+        0x28, 0x54, 0x54, 0x4C, 0x00, // 'g'
+        0x3F, 0x04, 0x04, 0x38, 0x00, // 'h'
+        0x00, 0x24, 0x3D, 0x20, 0x00, // 'i'
+        0x00, 0x20, 0x40, 0x3D, 0x00, // 'j'
+        0x3F, 0x08, 0x14, 0x20, 0x00, // 'k'
+        0x00, 0x21, 0x3F, 0x20, 0x00, // 'l'
+        0x3C, 0x08, 0x0C, 0x38, 0x00, // 'm'
+        0x3C, 0x04, 0x04, 0x38, 0x00, // 'n'
+        0x18, 0x24, 0x24, 0x18, 0x00, // 'o'
+        0x7C, 0x24, 0x24, 0x18, 0x00, // 'p'
+        0x18, 0x24, 0x24, 0x7C, 0x00, // 'q'
+        0x3C, 0x04, 0x04, 0x08, 0x00, // 'r'
+        0x28, 0x2C, 0x34, 0x14, 0x00, // 's'
+        0x04, 0x1F, 0x24, 0x20, 0x00, // 't'
+        0x1C, 0x20, 0x20, 0x3C, 0x00, // 'u'
+        0x00, 0x1C, 0x20, 0x1C, 0x00, // 'v'
+        0x3C, 0x30, 0x30, 0x3C, 0x00, // 'w'
+        0x24, 0x18, 0x18, 0x24, 0x00, // 'x'
+        0x0C, 0x50, 0x20, 0x1C, 0x00, // 'y'
+        0x24, 0x34, 0x2C, 0x24, 0x00, // 'z'
+        0x00, 0x04, 0x1E, 0x21, 0x00, // '{'
+        0x00, 0x00, 0x3F, 0x00, 0x00, // '|'
+        0x00, 0x21, 0x1E, 0x04, 0x00, // '}'
+        0x02, 0x01, 0x02, 0x01, 0x00, // '~'
+        0x00, 0x00, 0x00, 0x00, 0x00, // '\u{a0}'
+        0x00, 0x00, 0x3D, 0x00, 0x00, // '¡'
+        0x18, 0x24, 0x7E, 0x24, 0x00, // '¢'
+        0x28, 0x1C, 0x2A, 0x22, 0x00, // '£'
+        0x22, 0x1C, 0x14, 0x1C, 0x22, // '¤'
+        0x00, 0x0B, 0x3C, 0x0B, 0x00, // '¥'
+        0x00, 0x00, 0x36, 0x00, 0x00, // '¦'
+        0x00, 0x4E, 0x55, 0x39, 0x00, // '§'
+        0x00, 0x01, 0x00, 0x01, 0x00, // '¨'
+        0x3E, 0x49, 0x55, 0x41, 0x3E, // '©'
+        0x02, 0x05, 0x07, 0x00, 0x00, // 'ª'
+        0x08, 0x14, 0x00, 0x08, 0x14, // '«'
+        0x08, 0x08, 0x08, 0x18, 0x00, // '¬'
+        0x00, 0x08, 0x08, 0x08, 0x00, // '\u{ad}'
+        0x3E, 0x5D, 0x45, 0x41, 0x3E, // '®'
+        0x01, 0x01, 0x01, 0x01, 0x00, // '¯'
+        0x00, 0x02, 0x05, 0x02, 0x00, // '°'
+        0x24, 0x24, 0x3F, 0x24, 0x24, // '±'
+        0x00, 0x0D, 0x0B, 0x00, 0x00, // '²'
+        0x00, 0x0B, 0x0F, 0x00, 0x00, // '³'
+        0x00, 0x02, 0x01, 0x00, 0x00, // '´'
+        0x7C, 0x20, 0x20, 0x1C, 0x00, // 'µ'
+        0x06, 0x3F, 0x01, 0x3F, 0x00, // '¶'
+        0x00, 0x0C, 0x0C, 0x00, 0x00, // '·'
+        0x00, 0x40, 0x20, 0x00, 0x00, // '¸'
+        0x00, 0x0A, 0x0F, 0x08, 0x00, // '¹'
+        0x02, 0x05, 0x02, 0x00, 0x00, // 'º'
+        0x14, 0x08, 0x00, 0x14, 0x08, // '»'
+        0x0F, 0x20, 0x30, 0x78, 0x00, // '¼'
+        0x0F, 0x00, 0x68, 0x58, 0x00, // '½'
+        0x0B, 0x2F, 0x30, 0x78, 0x00, // '¾'
+        0x00, 0x18, 0x25, 0x10, 0x00, // '¿'
+        0x3D, 0x0A, 0x0A, 0x3D, 0x00, // 'Ä'
+        0x3C, 0x0B, 0x0B, 0x3C, 0x00, // 'Å'
+        0x3E, 0x09, 0x3F, 0x25, 0x00, // 'Æ'
+        0x1E, 0x61, 0x21, 0x12, 0x00, // 'Ç'
+        0x25, 0x3F, 0x21, 0x1E, 0x00, // 'Ð'
+        0x3F, 0x04, 0x19, 0x3F, 0x00, // 'Ñ'
+        0x1D, 0x22, 0x22, 0x1D, 0x00, // 'Ö'
+        0x24, 0x18, 0x18, 0x24, 0x00, // '×'
+        0x3E, 0x39, 0x27, 0x1F, 0x00, // 'Ø'
+        0x1D, 0x20, 0x20, 0x1D, 0x00, // 'Ü'
+        0x3F, 0x0A, 0x0A, 0x04, 0x00, // 'Þ'
+        0x3E, 0x01, 0x25, 0x1A, 0x00, // 'ß'
+        0x18, 0x25, 0x16, 0x3C, 0x00, // 'à'
+        0x18, 0x26, 0x15, 0x3C, 0x00, // 'á'
+        0x18, 0x26, 0x15, 0x3E, 0x00, // 'â'
+        0x1A, 0x25, 0x16, 0x3D, 0x00, // 'ã'
+        0x18, 0x25, 0x14, 0x3D, 0x00, // 'ä'
+        0x18, 0x27, 0x17, 0x3C, 0x00, // 'å'
+        0x18, 0x24, 0x3C, 0x2C, 0x00, // 'æ'
+        0x00, 0x18, 0x64, 0x24, 0x00, // 'ç'
+        0x18, 0x35, 0x2E, 0x08, 0x00, // 'è'
+        0x18, 0x36, 0x2D, 0x08, 0x00, // 'é'
+        0x1A, 0x35, 0x2E, 0x08, 0x00, // 'ê'
+        0x19, 0x34, 0x2D, 0x08, 0x00, // 'ë'
+        0x00, 0x25, 0x3E, 0x20, 0x00, // 'ì'
+        0x00, 0x26, 0x3D, 0x20, 0x00, // 'í'
+        0x00, 0x26, 0x3D, 0x22, 0x00, // 'î'
+        0x00, 0x25, 0x3C, 0x21, 0x00, // 'ï'
+        0x18, 0x25, 0x26, 0x1A, 0x00, // 'ð'
+        0x3E, 0x05, 0x06, 0x39, 0x00, // 'ñ'
+        0x18, 0x25, 0x26, 0x18, 0x00, // 'ò'
+        0x18, 0x26, 0x25, 0x18, 0x00, // 'ó'
+        0x18, 0x25, 0x25, 0x18, 0x00, // 'ô'
+        0x1A, 0x25, 0x26, 0x19, 0x00, // 'õ'
+        0x18, 0x25, 0x24, 0x19, 0x00, // 'ö'
+        0x08, 0x2A, 0x2A, 0x08, 0x00, // '÷'
+        0x38, 0x34, 0x2C, 0x1C, 0x00, // 'ø'
+        0x1C, 0x21, 0x22, 0x3C, 0x00, // 'ù'
+        0x1C, 0x22, 0x21, 0x3C, 0x00, // 'ú'
+        0x1C, 0x21, 0x21, 0x3C, 0x00, // 'û'
+        0x1C, 0x21, 0x20, 0x3D, 0x00, // 'ü'
+        0x0C, 0x52, 0x21, 0x1C, 0x00, // 'ý'
+        0x7E, 0x24, 0x24, 0x18, 0x00, // 'þ'
+        0x0C, 0x51, 0x20, 0x1D, 0x00, // 'ÿ'
+        0x00, 0x2E, 0x11, 0x2E, 0x00, // '\u{3a9}'
+        0x00, 0x2E, 0x11, 0x2E, 0x00, // '\u{2126}'
+    ],
+    widths: &[],
+    kerning: &[],
+    replacement: Some('?'),
+};
+
+/// 6x8 font, ASCII, part of Latin-1 and Omega
+pub static FONT_6X8: Font = Font {
+    width: 6,
+    height: 8,
+    ranges: &[(' ', '~', 0), ('\u{a0}', '»', 95), ('¿', '¿', 123), ('Æ', 'Ç', 124), ('Ð', 'Ð', 126), ('×', 'Ø', 127), ('Þ', 'ß', 129), ('ä', 'ä', 131), ('æ', 'ç', 132), ('ë', 'ë', 134), ('ï', 'ð', 135), ('ö', 'ø', 137), ('ü', 'ÿ', 140), ('\u{3a9}', '\u{3a9}', 144), ('\u{2126}', '\u{2126}', 145)],
+    data: &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+        0x00, 0x00, 0x5F, 0x00, 0x00, 0x00, // '!'
+        0x00, 0x07, 0x00, 0x07, 0x00, 0x00, // '"'
+        0x14, 0x7F, 0x14, 0x7F, 0x14, 0x00, // '#'
+        0x04, 0x2A, 0x7F, 0x2A, 0x10, 0x00, // '$'
+        0x62, 0x15, 0x2A, 0x54, 0x23, 0x00, // '%'
+        0x36, 0x49, 0x56, 0x20, 0x50, 0x00, // '&'
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, // '\''
+        0x00, 0x1C, 0x22, 0x41, 0x00, 0x00, // '('
+        0x00, 0x41, 0x22, 0x1C, 0x00, 0x00, // ')'
+        0x2A, 0x1C, 0x08, 0x1C, 0x2A, 0x00, // '*'
+        0x08, 0x08, 0x3E, 0x08, 0x08, 0x00, // '+'
+        0x00, 0x80, 0x60, 0x20, 0x00, 0x00, // ','
+        0x08, 0x08, 0x08, 0x08, 0x08, 0x00, // '-'
+        0x00, 0x40, 0xE0, 0x40, 0x00, 0x00, // '.'
+        0x60, 0x10, 0x08, 0x04, 0x03, 0x00, // '/'
+        0x1C, 0x22, 0x41, 0x22, 0x1C, 0x00, // '0'
+        0x44, 0x42, 0x7F, 0x40, 0x40, 0x00, // '1'
+        0x62, 0x51, 0x49, 0x49, 0x46, 0x00, // '2'
+        0x21, 0x41, 0x49, 0x4D, 0x33, 0x00, // '3'
+        0x18, 0x14, 0x12, 0x7F, 0x10, 0x00, // '4'
+        0x2F, 0x49, 0x45, 0x45, 0x39, 0x00, // '5'
+        0x3C, 0x52, 0x49, 0x49, 0x30, 0x00, // '6'
+        0x01, 0x61, 0x11, 0x0D, 0x03, 0x00, // '7'
+        0x36, 0x49, 0x49, 0x49, 0x36, 0x00, // '8'
+        0x06, 0x49, 0x49, 0x25, 0x1E, 0x00, // '9'
+        0x00, 0x44, 0xEE, 0x44, 0x00, 0x00, // ':'
+        0x00, 0x84, 0x6E, 0x24, 0x00, 0x00, // ';'
+        0x00, 0x08, 0x14, 0x22, 0x41, 0x00, // '<'
+        0x14, 0x14, 0x14, 0x14, 0x14, 0x00, // '='
+        0x00, 0x41, 0x22, 0x14, 0x08, 0x00, // '>'
+        0x02, 0x01, 0x59, 0x05, 0x02, 0x00, // '?'
+        0x3E, 0x41, 0x59, 0x55, 0x0E, 0x00, // '@'
+        0x7C, 0x12, 0x11, 0x12, 0x7C, 0x00, // 'A'
+        0x41, 0x7F, 0x49, 0x49, 0x36, 0x00, // 'B'
+        0x3E, 0x41, 0x41, 0x41, 0x22, 0x00, // 'C'
+        0x41, 0x7F, 0x41, 0x41, 0x3E, 0x00, // 'D'
+        0x7F, 0x49, 0x49, 0x49, 0x41, 0x00, // 'E'
+        0x7F, 0x09, 0x09, 0x09, 0x01, 0x00, // 'F'
+        0x3E, 0x41, 0x41, 0x51, 0x32, 0x00, // 'G'
+        0x7F, 0x08, 0x08, 0x08, 0x7F, 0x00, // 'H'
+        0x00, 0x41, 0x7F, 0x41, 0x00, 0x00, // 'I'
+        0x20, 0x40, 0x41, 0x3F, 0x01, 0x00, // 'J'
+        0x7F, 0x08, 0x14, 0x22, 0x41, 0x00, // 'K'
+        0x7F, 0x40, 0x40, 0x40, 0x40, 0x00, // 'L'
+        0x7F, 0x04, 0x08, 0x04, 0x7F, 0x00, // 'M'
+        0x7F, 0x04, 0x08, 0x10, 0x7F, 0x00, // 'N'
+        0x3E, 0x41, 0x41, 0x41, 0x3E, 0x00, // 'O'
+        0x7F, 0x09, 0x09, 0x09, 0x06, 0x00, // 'P'
+        0x3E, 0x41, 0x61, 0x41, 0xBE, 0x00, // 'Q'
+        0x7F, 0x09, 0x19, 0x29, 0x46, 0x00, // 'R'
+        0x26, 0x49, 0x49, 0x49, 0x32, 0x00, // 'S'
+        0x01, 0x01, 0x7F, 0x01, 0x01, 0x00, // 'T'
+        0x3F, 0x40, 0x40, 0x40, 0x3F, 0x00, // 'U'
+        0x07, 0x38, 0x40, 0x38, 0x07, 0x00, // 'V'
+        0x7F, 0x20, 0x18, 0x20, 0x7F, 0x00, // 'W'
+        0x63, 0x14, 0x08, 0x14, 0x63, 0x00, // 'X'
+        0x03, 0x04, 0x78, 0x04, 0x03, 0x00, // 'Y'
+        0x61, 0x51, 0x49, 0x45, 0x43, 0x00, // 'Z'
+        0x00, 0x7F, 0x41, 0x41, 0x00, 0x00, // '['
+        0x03, 0x04, 0x08, 0x10, 0x60, 0x00, // '\\'
+        0x00, 0x41, 0x41, 0x7F, 0x00, 0x00, // ']'
+        0x04, 0x02, 0x01, 0x02, 0x04, 0x00, // '^'
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x00, // '_'
+        0x00, 0x00, 0x01, 0x02, 0x00, 0x00, // '`'
+        0x20, 0x54, 0x54, 0x54, 0x78, 0x00, // 'a'
+        0x7F, 0x28, 0x44, 0x44, 0x38, 0x00, // 'b'
+        0x38, 0x44, 0x44, 0x44, 0x28, 0x00, // 'c'
+        0x38, 0x44, 0x44, 0x28, 0x7F, 0x00, // 'd'
+        0x38, 0x54, 0x54, 0x54, 0x18, 0x00, // 'e'
+        0x08, 0x7E, 0x09, 0x09, 0x02, 0x00, // 'f'
+        0x18, 0xA4, 0xA4, 0xA4, 0x7C, 0x00, // 'g'
+        0x7F, 0x08, 0x04, 0x04, 0x78, 0x00, // 'h'
+        0x00, 0x44, 0x7D, 0x40, 0x00, 0x00, // 'i'
+        0x00, 0x40, 0x80, 0x84, 0x7D, 0x00, // 'j'
+        0x7F, 0x10, 0x10, 0x28, 0x44, 0x00, // 'k'
+        0x00, 0x41, 0x7F, 0x40, 0x00, 0x00, // 'l'
+        0x7C, 0x04, 0x38, 0x04, 0x78, 0x00, // 'm'
+        0x7C, 0x08, 0x04, 0x04, 0x78, 0x00, // 'n'
+        0x38, 0x44, 0x44, 0x44, 0x38, 0x00, // 'o'
+        0xFC, 0x28, 0x44, 0x44, 0x38, 0x00, // 'p'
+        0x38, 0x44, 0x44, 0x28, 0xFC, 0x00, // 'q'
+        0x7C, 0x08, 0x04, 0x04, 0x08, 0x00, // 'r'
+        0x48, 0x54, 0x54, 0x54, 0x20, 0x00, // 's'
+        0x04, 0x3F, 0x44, 0x44, 0x20, 0x00, // 't'
+        0x3C, 0x40, 0x40, 0x20, 0x7C, 0x00, // 'u'
+        0x0C, 0x30, 0x40, 0x30, 0x0C, 0x00, // 'v'
+        0x3C, 0x40, 0x30, 0x40, 0x3C, 0x00, // 'w'
+        0x44, 0x28, 0x10, 0x28, 0x44, 0x00, // 'x'
+        0x1C, 0xA0, 0xA0, 0x90, 0x7C, 0x00, // 'y'
+        0x44, 0x64, 0x54, 0x4C, 0x44, 0x00, // 'z'
+        0x00, 0x08, 0x2A, 0x55, 0x41, 0x00, // '{'
+        0x00, 0x00, 0x7F, 0x00, 0x00, 0x00, // '|'
+        0x00, 0x41, 0x55, 0x2A, 0x08, 0x00, // '}'
+        0x06, 0x01, 0x02, 0x04, 0x03, 0x00, // '~'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\u{a0}'
+        0x00, 0x00, 0x7D, 0x00, 0x00, 0x00, // '¡'
+        0x38, 0x44, 0xFE, 0x44, 0x44, 0x00, // '¢'
+        0x48, 0x3E, 0x49, 0x41, 0x22, 0x00, // '£'
+        0x44, 0x38, 0x28, 0x38, 0x44, 0x00, // '¤'
+        0x13, 0x14, 0xF8, 0x14, 0x13, 0x00, // '¥'
+        0x00, 0x00, 0x77, 0x00, 0x00, 0x00, // '¦'
+        0x0E, 0x95, 0xA5, 0xA9, 0x70, 0x00, // '§'
+        0x00, 0x01, 0x00, 0x01, 0x00, 0x00, // '¨'
+        0x3E, 0x49, 0x55, 0x41, 0x3E, 0x00, // '©'
+        0x00, 0x26, 0x29, 0x25, 0x2F, 0x00, // 'ª'
+        0x10, 0x28, 0x44, 0x10, 0x28, 0x44, // '«'
+        0x00, 0x08, 0x08, 0x08, 0x18, 0x00, // '¬'
+        0x00, 0x08, 0x08, 0x08, 0x08, 0x00, // '\u{ad}'
+        0x3E, 0x5D, 0x45, 0x41, 0x3E, 0x00, // '®'
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x00, // '¯'
+        0x00, 0x02, 0x05, 0x02, 0x00, 0x00, // '°'
+        0x48, 0x48, 0x7E, 0x48, 0x48, 0x00, // '±'
+        0x00, 0x12, 0x19, 0x15, 0x12, 0x00, // '²'
+        0x00, 0x11, 0x15, 0x15, 0x0A, 0x00, // '³'
+        0x00, 0x00, 0x02, 0x01, 0x00, 0x00, // '´'
+        0xFC, 0x20, 0x40, 0x40, 0x3C, 0x00, // 'µ'
+        0x06, 0x0F, 0x7F, 0x01, 0x7F, 0x00, // '¶'
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, // '·'
+        0x00, 0x00, 0x80, 0x00, 0x00, 0x00, // '¸'
+        0x00, 0x12, 0x1F, 0x10, 0x00, 0x00, // '¹'
+        0x00, 0x26, 0x29, 0x29, 0x26, 0x00, // 'º'
+        0x44, 0x28, 0x10, 0x44, 0x28, 0x10, // '»'
+        0x20, 0x50, 0x4D, 0x40, 0x20, 0x00, // '¿'
+        0x7C, 0x12, 0x11, 0x7F, 0x49, 0x49, // 'Æ'
+        0x3E, 0xC1, 0x41, 0x41, 0x22, 0x00, // 'Ç'
+        0x49, 0x7F, 0x49, 0x41, 0x3E, 0x00, // 'Ð'
+        0x44, 0x28, 0x10, 0x28, 0x44, 0x00, // '×'
+        0x3E, 0x71, 0x49, 0x47, 0x3E, 0x00, // 'Ø'
+        0x7F, 0x0A, 0x0A, 0x0A, 0x04, 0x00, // 'Þ'
+        0x7E, 0x01, 0x49, 0x55, 0x22, 0x00, // 'ß'
+        0x20, 0x55, 0x54, 0x55, 0x78, 0x00, // 'ä'
+        0x20, 0x54, 0x54, 0x7C, 0x54, 0x58, // 'æ'
+        0x38, 0xC4, 0x44, 0x44, 0x28, 0x00, // 'ç'
+        0x38, 0x55, 0x54, 0x55, 0x18, 0x00, // 'ë'
+        0x00, 0x45, 0x7C, 0x41, 0x00, 0x00, // 'ï'
+        0x39, 0x45, 0x46, 0x46, 0x38, 0x00, // 'ð'
+        0x38, 0x45, 0x44, 0x45, 0x38, 0x00, // 'ö'
+        0x08, 0x08, 0x2A, 0x08, 0x08, 0x00, // '÷'
+        0x78, 0x64, 0x54, 0x4C, 0x3C, 0x00, // 'ø'
+        0x3C, 0x41, 0x40, 0x21, 0x7C, 0x00, // 'ü'
+        0x1C, 0xA0, 0xA2, 0x91, 0x7C, 0x00, // 'ý'
+        0xFE, 0x44, 0x44, 0x44, 0x38, 0x00, // 'þ'
+        0x1C, 0xA1, 0xA0, 0x91, 0x7C, 0x00, // 'ÿ'
+        0x5E, 0x61, 0x01, 0x61, 0x5E, 0x00, // '\u{3a9}'
+        0x5E, 0x61, 0x01, 0x61, 0x5E, 0x00, // '\u{2126}'
+    ],
+    widths: &[],
+    kerning: &[],
+    replacement: Some('?'),
+};
+
+/// 6x12 font, ASCII, Latin-1 and Omega
+pub static FONT_6X12: Font = Font {
+    width: 6,
+    height: 12,
+    ranges: &[(' ', '~', 0), ('\u{a0}', 'ÿ', 95), ('\u{3a9}', '\u{3a9}', 191), ('\u{2126}', '\u{2126}', 192)],
+    data: &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+        0x00, 0x00, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, // '!'
+        0x00, 0x1C, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '"'
+        0x20, 0xF0, 0x20, 0xF0, 0x20, 0x00, 0x01, 0x03, 0x01, 0x03, 0x01, 0x00, // '#'
+        0x30, 0x48, 0xFC, 0x48, 0x90, 0x00, 0x01, 0x02, 0x07, 0x02, 0x01, 0x00, // '$'
+        0x18, 0x98, 0x40, 0x20, 0x18, 0x00, 0x03, 0x00, 0x00, 0x03, 0x03, 0x00, // '%'
+        0xB0, 0x48, 0xB0, 0x00, 0x80, 0x00, 0x01, 0x02, 0x02, 0x01, 0x02, 0x00, // '&'
+        0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\''
+        0x00, 0xE0, 0x18, 0x04, 0x00, 0x00, 0x00, 0x00, 0x03, 0x04, 0x00, 0x00, // '('
+        0x00, 0x04, 0x18, 0xE0, 0x00, 0x00, 0x00, 0x04, 0x03, 0x00, 0x00, 0x00, // ')'
+        0x10, 0xA0, 0xF8, 0xA0, 0x10, 0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x00, // '*'
+        0x40, 0x40, 0xF0, 0x40, 0x40, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // '+'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x03, 0x00, 0x00, 0x00, // ','
+        0x40, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '-'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00, // '.'
+        0x00, 0x80, 0x40, 0x30, 0x08, 0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, // '/'
+        0x00, 0xF0, 0x08, 0x08, 0xF0, 0x00, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00, // '0'
+        0x00, 0x10, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // '1'
+        0x10, 0x08, 0x88, 0x48, 0x30, 0x00, 0x02, 0x03, 0x02, 0x02, 0x02, 0x00, // '2'
+        0x08, 0x08, 0x48, 0x68, 0x98, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '3'
+        0xC0, 0xA0, 0x90, 0xF8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, // '4'
+        0x38, 0x28, 0x28, 0x28, 0xC8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '5'
+        0xE0, 0x50, 0x48, 0x48, 0x80, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '6'
+        0x08, 0x08, 0x88, 0x68, 0x18, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // '7'
+        0xB0, 0x48, 0x48, 0x48, 0xB0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '8'
+        0x30, 0x48, 0x48, 0x48, 0xF0, 0x00, 0x00, 0x02, 0x02, 0x01, 0x00, 0x00, // '9'
+        0x00, 0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00, // ':'
+        0x00, 0x60, 0x60, 0x00, 0x00, 0x00, 0x04, 0x07, 0x03, 0x00, 0x00, 0x00, // ';'
+        0x00, 0x40, 0xA0, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, // '<'
+        0xA0, 0xA0, 0xA0, 0xA0, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '='
+        0x00, 0x10, 0xA0, 0x40, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, // '>'
+        0x10, 0x08, 0xC8, 0x28, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, // '?'
+        0xF0, 0x08, 0xE8, 0xA8, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // '@'
+        0xF0, 0x48, 0x48, 0x48, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'A'
+        0x08, 0xF8, 0x48, 0x48, 0xB0, 0x00, 0x02, 0x03, 0x02, 0x02, 0x01, 0x00, // 'B'
+        0xF0, 0x08, 0x08, 0x08, 0x10, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'C'
+        0x08, 0xF8, 0x08, 0x08, 0xF0, 0x00, 0x02, 0x03, 0x02, 0x02, 0x01, 0x00, // 'D'
+        0xF8, 0x48, 0x48, 0x48, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'E'
+        0xF8, 0x48, 0x48, 0x48, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // 'F'
+        0xF0, 0x08, 0x08, 0x88, 0x90, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'G'
+        0xF8, 0x40, 0x40, 0x40, 0xF8, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'H'
+        0x00, 0x08, 0xF8, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'I'
+        0x00, 0x00, 0x08, 0xF8, 0x08, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00, 0x00, // 'J'
+        0xF8, 0x40, 0xA0, 0x10, 0x08, 0x00, 0x03, 0x00, 0x00, 0x01, 0x02, 0x00, // 'K'
+        0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'L'
+        0xF8, 0x10, 0x20, 0x10, 0xF8, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'M'
+        0xF8, 0x20, 0x40, 0x80, 0xF8, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'N'
+        0xF0, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'O'
+        0xF8, 0x48, 0x48, 0x48, 0x30, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // 'P'
+        0xF0, 0x08, 0x88, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x01, 0x02, 0x00, // 'Q'
+        0xF8, 0x48, 0xC8, 0x48, 0x30, 0x00, 0x03, 0x00, 0x00, 0x01, 0x02, 0x00, // 'R'
+        0x30, 0x48, 0x48, 0x48, 0x90, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'S'
+        0x08, 0x08, 0xF8, 0x08, 0x08, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // 'T'
+        0xF8, 0x00, 0x00, 0x00, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'U'
+        0x78, 0x80, 0x00, 0x80, 0x78, 0x00, 0x00, 0x01, 0x02, 0x01, 0x00, 0x00, // 'V'
+        0xF8, 0x00, 0x80, 0x00, 0xF8, 0x00, 0x01, 0x02, 0x01, 0x02, 0x01, 0x00, // 'W'
+        0x18, 0xA0, 0x40, 0xA0, 0x18, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'X'
+        0x18, 0x20, 0xC0, 0x20, 0x18, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // 'Y'
+        0x08, 0x88, 0x48, 0x28, 0x18, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'Z'
+        0x00, 0xFC, 0x04, 0x04, 0x00, 0x00, 0x00, 0x07, 0x04, 0x04, 0x00, 0x00, // '['
+        0x08, 0x30, 0x40, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, // '\\'
+        0x00, 0x04, 0x04, 0xFC, 0x00, 0x00, 0x00, 0x04, 0x04, 0x07, 0x00, 0x00, // ']'
+        0x10, 0x08, 0x04, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '^'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, // '_'
+        0x00, 0x04, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '`'
+        0x00, 0xA0, 0xA0, 0xA0, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'a'
+        0xF8, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x03, 0x02, 0x02, 0x02, 0x01, 0x00, // 'b'
+        0xC0, 0x20, 0x20, 0x20, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'c'
+        0xC0, 0x20, 0x20, 0x20, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'd'
+        0xC0, 0xA0, 0xA0, 0xA0, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'e'
+        0x40, 0xF0, 0x48, 0x08, 0x10, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, // 'f'
+        0xC0, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x01, 0x0A, 0x0A, 0x0A, 0x07, 0x00, // 'g'
+        0xF8, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'h'
+        0x00, 0x20, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'i'
+        0x00, 0x00, 0x00, 0x20, 0xE8, 0x00, 0x00, 0x04, 0x08, 0x08, 0x07, 0x00, // 'j'
+        0xF8, 0x80, 0x80, 0x40, 0x20, 0x00, 0x03, 0x00, 0x00, 0x01, 0x02, 0x00, // 'k'
+        0x00, 0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'l'
+        0xE0, 0x20, 0xC0, 0x20, 0xC0, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, // 'm'
+        0xE0, 0x40, 0x20, 0x20, 0xC0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'n'
+        0xC0, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'o'
+        0xE0, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x01, 0x00, // 'p'
+        0xC0, 0x20, 0x20, 0x20, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'q'
+        0xE0, 0x40, 0x20, 0x20, 0x40, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // 'r'
+        0x40, 0xA0, 0xA0, 0xA0, 0x20, 0x00, 0x02, 0x02, 0x02, 0x02, 0x01, 0x00, // 's'
+        0x20, 0x20, 0xF8, 0x20, 0x20, 0x00, 0x00, 0x00, 0x01, 0x02, 0x02, 0x00, // 't'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x01, 0x03, 0x00, // 'u'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x00, 0x01, 0x02, 0x01, 0x00, 0x00, // 'v'
+        0xE0, 0x00, 0x80, 0x00, 0xE0, 0x00, 0x01, 0x02, 0x01, 0x02, 0x01, 0x00, // 'w'
+        0x20, 0x40, 0x80, 0x40, 0x20, 0x00, 0x02, 0x01, 0x00, 0x01, 0x02, 0x00, // 'x'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x08, 0x05, 0x02, 0x01, 0x00, 0x00, // 'y'
+        0x20, 0x20, 0xA0, 0x60, 0x20, 0x00, 0x02, 0x03, 0x02, 0x02, 0x02, 0x00, // 'z'
+        0x00, 0x40, 0xB8, 0x04, 0x00, 0x00, 0x00, 0x00, 0x03, 0x04, 0x00, 0x00, // '{'
+        0x00, 0x00, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, // '|'
+        0x00, 0x04, 0xB8, 0x40, 0x00, 0x00, 0x00, 0x04, 0x03, 0x00, 0x00, 0x00, // '}'
+        0xC0, 0x20, 0x40, 0x80, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '~'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\u{a0}'
+        0x00, 0x00, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // '¡'
+        0xC0, 0x20, 0xF0, 0x20, 0x40, 0x00, 0x01, 0x02, 0x07, 0x02, 0x01, 0x00, // '¢'
+        0x40, 0xF0, 0x48, 0x08, 0x10, 0x00, 0x02, 0x01, 0x02, 0x02, 0x01, 0x00, // '£'
+        0xA0, 0x40, 0x20, 0x40, 0xA0, 0x00, 0x02, 0x01, 0x02, 0x01, 0x02, 0x00, // '¤'
+        0xA8, 0xB0, 0xE0, 0xB0, 0xA8, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // '¥'
+        0x00, 0x00, 0xB8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // '¦'
+        0x00, 0x68, 0x94, 0x94, 0x64, 0x00, 0x00, 0x02, 0x02, 0x02, 0x01, 0x00, // '§'
+        0x00, 0x04, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¨'
+        0xF0, 0x08, 0x48, 0xA8, 0x08, 0xF0, 0x01, 0x02, 0x02, 0x02, 0x02, 0x01, // '©'
+        0x00, 0x24, 0x2A, 0x2E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 'ª'
+        0x80, 0x40, 0xA0, 0x40, 0x20, 0x00, 0x00, 0x01, 0x02, 0x01, 0x02, 0x00, // '«'
+        0x40, 0x40, 0x40, 0x40, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, // '¬'
+        0x00, 0x40, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\u{ad}'
+        0xF0, 0x08, 0xE8, 0x28, 0x08, 0xF0, 0x01, 0x02, 0x02, 0x02, 0x02, 0x01, // '®'
+        0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¯'
+        0x00, 0x0C, 0x12, 0x12, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '°'
+        0x20, 0x20, 0xF8, 0x20, 0x20, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x00, // '±'
+        0x00, 0x12, 0x19, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '²'
+        0x00, 0x11, 0x15, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '³'
+        0x00, 0x10, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '´'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x0F, 0x02, 0x02, 0x01, 0x03, 0x00, // 'µ'
+        0x38, 0x7C, 0xFC, 0x04, 0xFC, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, // '¶'
+        0x00, 0x00, 0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '·'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x04, 0x00, 0x00, // '¸'
+        0x00, 0x12, 0x1F, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¹'
+        0x00, 0x12, 0x15, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 'º'
+        0x20, 0x40, 0xA0, 0x40, 0x80, 0x00, 0x02, 0x01, 0x02, 0x01, 0x00, 0x00, // '»'
+        0x02, 0xDF, 0xA0, 0xF0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, // '¼'
+        0x02, 0x1F, 0x20, 0x90, 0x60, 0x00, 0x00, 0x00, 0x03, 0x02, 0x02, 0x00, // '½'
+        0x11, 0xD5, 0xAA, 0xF0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, // '¾'
+        0x00, 0x80, 0x68, 0x00, 0x00, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '¿'
+        0xF0, 0x49, 0x4A, 0x48, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'À'
+        0xF0, 0x48, 0x4A, 0x49, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Á'
+        0xF0, 0x4A, 0x49, 0x4A, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Â'
+        0xF2, 0x49, 0x4B, 0x4A, 0xF1, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Ã'
+        0xF0, 0x4A, 0x48, 0x4A, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Ä'
+        0xF0, 0x4A, 0x4D, 0x4A, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Å'
+        0xF0, 0x48, 0xF8, 0x48, 0x08, 0x00, 0x03, 0x00, 0x03, 0x02, 0x02, 0x00, // 'Æ'
+        0xF0, 0x08, 0x08, 0x08, 0x10, 0x00, 0x01, 0x0A, 0x0A, 0x06, 0x01, 0x00, // 'Ç'
+        0xF8, 0x49, 0x4A, 0x48, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'È'
+        0xF8, 0x48, 0x4A, 0x49, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'É'
+        0xF8, 0x4A, 0x49, 0x4A, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'Ê'
+        0xF8, 0x4A, 0x48, 0x4A, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'Ë'
+        0x00, 0x09, 0xFA, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'Ì'
+        0x00, 0x08, 0xFA, 0x09, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'Í'
+        0x00, 0x0A, 0xF9, 0x0A, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'Î'
+        0x00, 0x0A, 0xF8, 0x0A, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'Ï'
+        0x40, 0xF8, 0x48, 0x08, 0xF0, 0x00, 0x00, 0x03, 0x02, 0x02, 0x01, 0x00, // 'Ð'
+        0xFA, 0x21, 0x43, 0x82, 0xF9, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Ñ'
+        0xF0, 0x09, 0x0A, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ò'
+        0xF0, 0x08, 0x0A, 0x09, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ó'
+        0xF0, 0x0A, 0x09, 0x0A, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ô'
+        0xF2, 0x09, 0x0B, 0x0A, 0xF1, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Õ'
+        0xF0, 0x0A, 0x08, 0x0A, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ö'
+        0x10, 0xA0, 0x40, 0xA0, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, // '×'
+        0xF0, 0x08, 0xE8, 0x18, 0xF4, 0x00, 0x05, 0x03, 0x02, 0x02, 0x01, 0x00, // 'Ø'
+        0xF8, 0x01, 0x02, 0x00, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ù'
+        0xF8, 0x00, 0x02, 0x01, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ú'
+        0xF8, 0x02, 0x01, 0x02, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Û'
+        0xF8, 0x02, 0x00, 0x02, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ü'
+        0x18, 0x20, 0xC2, 0x21, 0x18, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // 'Ý'
+        0x00, 0xF8, 0x10, 0x10, 0xE0, 0x00, 0x00, 0x03, 0x01, 0x01, 0x00, 0x00, // 'Þ'
+        0xF0, 0x08, 0x48, 0xA8, 0x10, 0x00, 0x03, 0x00, 0x02, 0x02, 0x01, 0x00, // 'ß'
+        0x00, 0xA4, 0xA8, 0xA0, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'à'
+        0x00, 0xA0, 0xA8, 0xA4, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'á'
+        0x00, 0xA8, 0xA4, 0xA8, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'â'
+        0x08, 0xA4, 0xAC, 0xA8, 0xC4, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'ã'
+        0x00, 0xA8, 0xA0, 0xA8, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'ä'
+        0x00, 0xA8, 0xB4, 0xA8, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'å'
+        0x00, 0xA0, 0xE0, 0xA0, 0x40, 0x00, 0x01, 0x02, 0x03, 0x02, 0x02, 0x00, // 'æ'
+        0xC0, 0x20, 0x20, 0x20, 0x40, 0x00, 0x01, 0x0A, 0x0A, 0x06, 0x01, 0x00, // 'ç'
+        0xC0, 0xA4, 0xA8, 0xA0, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'è'
+        0xC0, 0xA0, 0xA8, 0xA4, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'é'
+        0xC0, 0xA8, 0xA4, 0xA8, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'ê'
+        0xC0, 0xA8, 0xA0, 0xA8, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'ë'
+        0x00, 0x24, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'ì'
+        0x00, 0x20, 0xE8, 0x04, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'í'
+        0x00, 0x28, 0xE4, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'î'
+        0x00, 0x28, 0xE0, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, // 'ï'
+        0xC0, 0x2A, 0x24, 0x2A, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ð'
+        0xE8, 0x44, 0x2C, 0x28, 0xC4, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'ñ'
+        0xC0, 0x24, 0x28, 0x20, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ò'
+        0xC0, 0x20, 0x28, 0x24, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ó'
+        0xC0, 0x28, 0x24, 0x28, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ô'
+        0xC8, 0x24, 0x2C, 0x28, 0xC4, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'õ'
+        0xC0, 0x28, 0x20, 0x28, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ö'
+        0x40, 0x40, 0x50, 0x40, 0x40, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // '÷'
+        0xC0, 0x20, 0xA0, 0x60, 0xE0, 0x00, 0x03, 0x03, 0x02, 0x02, 0x01, 0x00, // 'ø'
+        0xE0, 0x04, 0x08, 0x00, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ù'
+        0xE0, 0x00, 0x08, 0x04, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ú'
+        0xE0, 0x08, 0x04, 0x08, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'û'
+        0xE0, 0x08, 0x00, 0x08, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ü'
+        0xE0, 0x00, 0x08, 0x04, 0xE0, 0x00, 0x08, 0x05, 0x02, 0x01, 0x00, 0x00, // 'ý'
+        0xF8, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x01, 0x00, // 'þ'
+        0xE0, 0x08, 0x00, 0x08, 0xE0, 0x00, 0x08, 0x05, 0x02, 0x01, 0x00, 0x00, // 'ÿ'
+        0xF0, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x02, 0x03, 0x00, 0x03, 0x02, 0x00, // '\u{3a9}'
+        0xF0, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x02, 0x03, 0x00, 0x03, 0x02, 0x00, // '\u{2126}'
+    ],
+    widths: &[],
+    kerning: &[],
+    replacement: Some('?'),
+};
+
+/// 8x16 font, ASCII, Latin-1 and Omega
+pub static FONT_8X16: Font = Font {
+    width: 8,
+    height: 16,
+    ranges: &[(' ', '~', 0), ('\u{a0}', 'ÿ', 95), ('\u{3a9}', '\u{3a9}', 191), ('\u{2126}', '\u{2126}', 192)],
+    data: &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+        0x00, 0x00, 0x00, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0x00, 0x00, 0x00, 0x00, // '!'
+        0x00, 0x00, 0x38, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '"'
+        0x00, 0x40, 0xF0, 0x40, 0x40, 0xF0, 0x40, 0x00, 0x00, 0x01, 0x07, 0x01, 0x01, 0x07, 0x01, 0x00, // '#'
+        0x00, 0x60, 0x90, 0xF8, 0x90, 0x10, 0x00, 0x00, 0x00, 0x04, 0x04, 0x0F, 0x04, 0x03, 0x00, 0x00, // '$'
+        0x00, 0x10, 0x28, 0x10, 0xC0, 0x20, 0x18, 0x00, 0x00, 0x08, 0x06, 0x01, 0x04, 0x0A, 0x04, 0x00, // '%'
+        0x00, 0xC0, 0x20, 0x20, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x06, 0x09, 0x09, 0x0A, 0x04, 0x0A, 0x00, // '&'
+        0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\''
+        0x00, 0x00, 0x00, 0xC0, 0x30, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x06, 0x08, 0x00, 0x00, // '('
+        0x00, 0x00, 0x08, 0x30, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x06, 0x01, 0x00, 0x00, 0x00, // ')'
+        0x00, 0x20, 0xA8, 0x70, 0x70, 0xA8, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '*'
+        0x00, 0x80, 0x80, 0xE0, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, // '+'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0C, 0x0C, 0x04, 0x00, 0x00, 0x00, // ','
+        0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '-'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x1C, 0x08, 0x00, 0x00, 0x00, // '.'
+        0x00, 0x00, 0x00, 0x80, 0x40, 0x20, 0x18, 0x00, 0x0C, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // '/'
+        0x00, 0xE0, 0x10, 0x08, 0x08, 0x10, 0xE0, 0x00, 0x00, 0x03, 0x04, 0x08, 0x08, 0x04, 0x03, 0x00, // '0'
+        0x00, 0x20, 0x10, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // '1'
+        0x00, 0x30, 0x08, 0x08, 0x08, 0x88, 0x70, 0x00, 0x00, 0x0C, 0x0A, 0x09, 0x09, 0x08, 0x08, 0x00, // '2'
+        0x00, 0x08, 0x08, 0x88, 0xC8, 0xA8, 0x18, 0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // '3'
+        0x00, 0x80, 0x40, 0x20, 0x10, 0xF8, 0x00, 0x00, 0x00, 0x03, 0x02, 0x02, 0x02, 0x0F, 0x02, 0x00, // '4'
+        0x00, 0xF8, 0x88, 0x48, 0x48, 0x48, 0x88, 0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // '5'
+        0x00, 0xE0, 0x10, 0x88, 0x88, 0x88, 0x00, 0x00, 0x00, 0x07, 0x09, 0x08, 0x08, 0x08, 0x07, 0x00, // '6'
+        0x00, 0x08, 0x08, 0x08, 0xC8, 0x28, 0x18, 0x00, 0x00, 0x00, 0x0C, 0x03, 0x00, 0x00, 0x00, 0x00, // '7'
+        0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // '8'
+        0x00, 0x70, 0x88, 0x88, 0x88, 0x48, 0xF0, 0x00, 0x00, 0x00, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00, // '9'
+        0x00, 0x00, 0x40, 0xE0, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x1C, 0x08, 0x00, 0x00, 0x00, // ':'
+        0x00, 0x00, 0x40, 0xE0, 0x40, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0C, 0x0C, 0x04, 0x00, 0x00, 0x00, // ';'
+        0x00, 0x00, 0x80, 0x40, 0x20, 0x10, 0x08, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00, // '<'
+        0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x00, // '='
+        0x00, 0x08, 0x10, 0x20, 0x40, 0x80, 0x00, 0x00, 0x00, 0x08, 0x04, 0x02, 0x01, 0x00, 0x00, 0x00, // '>'
+        0x00, 0x30, 0x08, 0x08, 0x08, 0x88, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0B, 0x00, 0x00, 0x00, // '?'
+        0x00, 0xF0, 0x08, 0x88, 0x48, 0x48, 0xF0, 0x00, 0x00, 0x07, 0x08, 0x09, 0x0A, 0x09, 0x03, 0x00, // '@'
+        0x00, 0xE0, 0x10, 0x08, 0x08, 0x10, 0xE0, 0x00, 0x00, 0x0F, 0x01, 0x01, 0x01, 0x01, 0x0F, 0x00, // 'A'
+        0x00, 0xF8, 0x88, 0x88, 0x88, 0x50, 0x20, 0x00, 0x00, 0x0F, 0x08, 0x08, 0x08, 0x05, 0x02, 0x00, // 'B'
+        0x00, 0xF0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x04, 0x00, // 'C'
+        0x00, 0xF8, 0x08, 0x08, 0x08, 0x10, 0xE0, 0x00, 0x00, 0x0F, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00, // 'D'
+        0x00, 0xF8, 0x88, 0x88, 0x88, 0x08, 0x08, 0x00, 0x00, 0x0F, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, // 'E'
+        0x00, 0xF8, 0x88, 0x88, 0x88, 0x08, 0x08, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 'F'
+        0x00, 0xF0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00, 0x00, 0x07, 0x08, 0x08, 0x09, 0x05, 0x0F, 0x00, // 'G'
+        0x00, 0xF8, 0x80, 0x80, 0x80, 0x80, 0xF8, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, // 'H'
+        0x00, 0x08, 0x08, 0xF8, 0x08, 0x08, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'I'
+        0x00, 0x00, 0x00, 0x08, 0x08, 0xF8, 0x08, 0x08, 0x00, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00, 0x00, // 'J'
+        0x00, 0xF8, 0x80, 0x40, 0x20, 0x10, 0x08, 0x00, 0x00, 0x0F, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00, // 'K'
+        0x00, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, // 'L'
+        0xF8, 0x20, 0x40, 0x80, 0x40, 0x20, 0xF8, 0x00, 0x0F, 0x00, 0x00, 0x01, 0x00, 0x00, 0x0F, 0x00, // 'M'
+        0x00, 0xF8, 0x20, 0x40, 0x80, 0x00, 0xF8, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x01, 0x0F, 0x00, // 'N'
+        0x00, 0xF0, 0x08, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'O'
+        0x00, 0xF8, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 'P'
+        0x00, 0xF0, 0x08, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x00, 0x07, 0x08, 0x0A, 0x0C, 0x08, 0x17, 0x00, // 'Q'
+        0x00, 0xF8, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, 0x0F, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00, // 'R'
+        0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x10, 0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'S'
+        0x08, 0x08, 0x08, 0xF8, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, // 'T'
+        0x00, 0xF8, 0x00, 0x00, 0x00, 0x00, 0xF8, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'U'
+        0x18, 0xE0, 0x00, 0x00, 0x00, 0xE0, 0x18, 0x00, 0x00, 0x00, 0x07, 0x08, 0x07, 0x00, 0x00, 0x00, // 'V'
+        0xF8, 0x00, 0x00, 0x80, 0x00, 0x00, 0xF8, 0x00, 0x07, 0x08, 0x04, 0x03, 0x04, 0x08, 0x07, 0x00, // 'W'
+        0x18, 0x20, 0x40, 0x80, 0x40, 0x20, 0x18, 0x00, 0x0C, 0x02, 0x01, 0x00, 0x01, 0x02, 0x0C, 0x00, // 'X'
+        0x18, 0x20, 0x40, 0x80, 0x40, 0x20, 0x18, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, // 'Y'
+        0x00, 0x08, 0x08, 0x88, 0x48, 0x28, 0x18, 0x00, 0x00, 0x0E, 0x09, 0x08, 0x08, 0x08, 0x08, 0x00, // 'Z'
+        0x00, 0x00, 0xF8, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x08, 0x08, 0x08, 0x00, 0x00, // '['
+        0x18, 0x20, 0x40, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x0C, 0x00, // '\\'
+        0x00, 0x08, 0x08, 0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x08, 0x0F, 0x00, 0x00, 0x00, // ']'
+        0x00, 0x20, 0x10, 0x08, 0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '^'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00, // '_'
+        0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '`'
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0F, 0x00, // 'a'
+        0x00, 0xF8, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x0F, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00, // 'b'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x04, 0x00, // 'c'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0xF8, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x04, 0x0F, 0x00, // 'd'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00, // 'e'
+        0x00, 0x80, 0xF0, 0x88, 0x88, 0x88, 0x10, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, // 'f'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0x40, 0x00, 0x00, 0x15, 0x2A, 0x2A, 0x2A, 0x29, 0x10, 0x00, // 'g'
+        0x00, 0xF8, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, // 'h'
+        0x00, 0x00, 0x40, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'i'
+        0x00, 0x00, 0x00, 0x00, 0x40, 0xD0, 0x00, 0x00, 0x00, 0x18, 0x20, 0x20, 0x20, 0x1F, 0x00, 0x00, // 'j'
+        0x00, 0xF8, 0x00, 0x00, 0x80, 0x40, 0x00, 0x00, 0x00, 0x0F, 0x01, 0x01, 0x02, 0x04, 0x08, 0x00, // 'k'
+        0x00, 0x00, 0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'l'
+        0xC0, 0x40, 0x40, 0x80, 0x40, 0x40, 0x80, 0x00, 0x0F, 0x00, 0x00, 0x07, 0x00, 0x00, 0x0F, 0x00, // 'm'
+        0x00, 0xC0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, // 'n'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'o'
+        0x00, 0xC0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x3F, 0x02, 0x04, 0x04, 0x04, 0x03, 0x00, // 'p'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0xC0, 0x00, 0x00, 0x03, 0x04, 0x04, 0x04, 0x02, 0x3F, 0x00, // 'q'
+        0x00, 0x40, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, // 'r'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x04, 0x09, 0x09, 0x0A, 0x0A, 0x04, 0x00, // 's'
+        0x00, 0x40, 0xF0, 0x40, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x04, 0x00, // 't'
+        0x00, 0xC0, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00, // 'u'
+        0x00, 0xC0, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x01, 0x06, 0x08, 0x06, 0x01, 0x00, 0x00, // 'v'
+        0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x07, 0x08, 0x04, 0x03, 0x04, 0x08, 0x07, 0x00, // 'w'
+        0x00, 0x40, 0x80, 0x00, 0x00, 0x80, 0x40, 0x00, 0x00, 0x08, 0x04, 0x03, 0x03, 0x04, 0x08, 0x00, // 'x'
+        0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x13, 0x24, 0x24, 0x24, 0x22, 0x1F, 0x00, // 'y'
+        0x00, 0x40, 0x40, 0x40, 0x40, 0xC0, 0x40, 0x00, 0x00, 0x08, 0x0C, 0x0A, 0x09, 0x08, 0x08, 0x00, // 'z'
+        0x00, 0x00, 0x80, 0xB0, 0x48, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x06, 0x09, 0x08, 0x08, 0x00, // '{'
+        0x00, 0x00, 0x00, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, // '|'
+        0x00, 0x08, 0x08, 0x48, 0xB0, 0x80, 0x00, 0x00, 0x00, 0x08, 0x08, 0x09, 0x06, 0x00, 0x00, 0x00, // '}'
+        0x00, 0x30, 0x08, 0x10, 0x20, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '~'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\u{a0}'
+        0x00, 0x00, 0x00, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, // '¡'
+        0x00, 0xE0, 0x10, 0xF8, 0x10, 0x20, 0x00, 0x00, 0x00, 0x01, 0x02, 0x07, 0x02, 0x01, 0x00, 0x00, // '¢'
+        0x00, 0x40, 0xF0, 0x48, 0x08, 0x08, 0x10, 0x00, 0x08, 0x0C, 0x07, 0x08, 0x08, 0x08, 0x04, 0x00, // '£'
+        0x00, 0x20, 0xC0, 0x40, 0x40, 0xC0, 0x20, 0x00, 0x00, 0x04, 0x03, 0x02, 0x02, 0x03, 0x04, 0x00, // '¤'
+        0x18, 0xA0, 0xC0, 0x80, 0xC0, 0xA0, 0x18, 0x00, 0x00, 0x02, 0x02, 0x0F, 0x02, 0x02, 0x00, 0x00, // '¥'
+        0x00, 0x00, 0x00, 0x78, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, // '¦'
+        0x00, 0x00, 0xD8, 0x24, 0x24, 0xC8, 0x00, 0x00, 0x00, 0x00, 0x04, 0x09, 0x09, 0x06, 0x00, 0x00, // '§'
+        0x00, 0x00, 0x0C, 0x00, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¨'
+        0xF0, 0x08, 0xE4, 0x14, 0xA4, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x04, 0x05, 0x04, 0x02, 0x01, 0x00, // '©'
+        0x00, 0x40, 0xA8, 0xA8, 0xA8, 0xF0, 0x00, 0x00, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x00, 0x00, // 'ª'
+        0x80, 0x40, 0x20, 0x90, 0x40, 0x20, 0x10, 0x00, 0x00, 0x01, 0x02, 0x04, 0x01, 0x02, 0x04, 0x00, // '«'
+        0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, // '¬'
+        0x00, 0x00, 0x80, 0x80, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\u{ad}'
+        0xF0, 0x08, 0xE4, 0x94, 0x64, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x05, 0x04, 0x05, 0x02, 0x01, 0x00, // '®'
+        0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¯'
+        0x00, 0x00, 0x30, 0x48, 0x48, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '°'
+        0x00, 0x40, 0x40, 0xF0, 0x40, 0x40, 0x00, 0x00, 0x00, 0x04, 0x04, 0x05, 0x04, 0x04, 0x00, 0x00, // '±'
+        0x00, 0xC8, 0xA4, 0xA4, 0x98, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '²'
+        0x00, 0x48, 0x84, 0x94, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '³'
+        0x00, 0x00, 0x00, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '´'
+        0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x1F, 0x04, 0x08, 0x08, 0x04, 0x0F, 0x00, // 'µ'
+        0x00, 0x70, 0xF8, 0xF8, 0x08, 0xF8, 0x08, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x0F, 0x00, 0x00, // '¶'
+        0x00, 0x00, 0x00, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '·'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x30, 0x00, 0x00, 0x00, // '¸'
+        0x00, 0x88, 0xFC, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¹'
+        0x00, 0x30, 0x48, 0x48, 0x30, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, // 'º'
+        0x10, 0x20, 0x40, 0x90, 0x20, 0x40, 0x80, 0x00, 0x04, 0x02, 0x01, 0x04, 0x02, 0x01, 0x00, 0x00, // '»'
+        0x88, 0xFC, 0x80, 0x00, 0x00, 0x80, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x06, 0x05, 0x08, 0x0F, 0x00, // '¼'
+        0x88, 0xFC, 0x80, 0x80, 0x40, 0x40, 0x80, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0A, 0x0A, 0x09, 0x00, // '½'
+        0x48, 0x84, 0x94, 0x68, 0x00, 0x80, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x06, 0x05, 0x08, 0x0F, 0x00, // '¾'
+        0x00, 0x00, 0x80, 0x68, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x06, 0x00, // '¿'
+        0x00, 0x80, 0x40, 0x24, 0x28, 0x40, 0x80, 0x00, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'À'
+        0x00, 0x80, 0x40, 0x28, 0x24, 0x40, 0x80, 0x00, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'Á'
+        0x00, 0x80, 0x48, 0x24, 0x24, 0x48, 0x80, 0x00, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'Â'
+        0x00, 0x88, 0x44, 0x24, 0x28, 0x48, 0x84, 0x00, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'Ã'
+        0x00, 0x80, 0x4C, 0x20, 0x20, 0x4C, 0x80, 0x00, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'Ä'
+        0x00, 0x80, 0x48, 0x34, 0x34, 0x48, 0x80, 0x00, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'Å'
+        0xF0, 0x08, 0x08, 0xF0, 0x88, 0x88, 0x08, 0x00, 0x0F, 0x01, 0x01, 0x0F, 0x08, 0x08, 0x08, 0x00, // 'Æ'
+        0x00, 0xF0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00, 0x00, 0x07, 0x08, 0x28, 0x18, 0x08, 0x04, 0x00, // 'Ç'
+        0x00, 0xE0, 0x20, 0x24, 0x28, 0x20, 0x20, 0x00, 0x00, 0x0F, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00, // 'È'
+        0x00, 0xE0, 0x20, 0x28, 0x24, 0x20, 0x20, 0x00, 0x00, 0x0F, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00, // 'É'
+        0x00, 0xE0, 0x28, 0x24, 0x24, 0x28, 0x20, 0x00, 0x00, 0x0F, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00, // 'Ê'
+        0x00, 0xE0, 0x2C, 0x20, 0x20, 0x2C, 0x20, 0x00, 0x00, 0x0F, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00, // 'Ë'
+        0x00, 0x20, 0x24, 0xE8, 0x20, 0x20, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'Ì'
+        0x00, 0x20, 0x20, 0xE8, 0x24, 0x20, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'Í'
+        0x00, 0x20, 0x28, 0xE4, 0x24, 0x28, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'Î'
+        0x00, 0x2C, 0x20, 0xE0, 0x20, 0x2C, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'Ï'
+        0x80, 0xF8, 0x88, 0x08, 0x08, 0x10, 0xE0, 0x00, 0x00, 0x0F, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00, // 'Ð'
+        0xE8, 0x44, 0x84, 0x08, 0x08, 0x04, 0xE0, 0x00, 0x0F, 0x00, 0x00, 0x01, 0x02, 0x04, 0x0F, 0x00, // 'Ñ'
+        0xC0, 0x20, 0x24, 0x28, 0x20, 0x20, 0xC0, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Ò'
+        0xC0, 0x20, 0x20, 0x28, 0x24, 0x20, 0xC0, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Ó'
+        0xC0, 0x20, 0x28, 0x24, 0x24, 0x28, 0xC0, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Ô'
+        0xC8, 0x24, 0x24, 0x28, 0x28, 0x24, 0xC0, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Õ'
+        0xC0, 0x2C, 0x20, 0x20, 0x20, 0x2C, 0xC0, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Ö'
+        0x00, 0x20, 0x40, 0x80, 0x80, 0x40, 0x20, 0x00, 0x00, 0x04, 0x02, 0x01, 0x01, 0x02, 0x04, 0x00, // '×'
+        0x00, 0xF0, 0x08, 0x88, 0x68, 0x18, 0xF4, 0x00, 0x00, 0x17, 0x0C, 0x0B, 0x08, 0x08, 0x07, 0x00, // 'Ø'
+        0x00, 0xE0, 0x04, 0x08, 0x00, 0x00, 0xE0, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Ù'
+        0x00, 0xE0, 0x00, 0x08, 0x04, 0x00, 0xE0, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Ú'
+        0x00, 0xE0, 0x08, 0x04, 0x04, 0x08, 0xE0, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Û'
+        0x00, 0xE0, 0x0C, 0x00, 0x00, 0x0C, 0xE0, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'Ü'
+        0x00, 0x60, 0x80, 0x08, 0x84, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, // 'Ý'
+        0x00, 0xF8, 0x10, 0x10, 0x10, 0x10, 0xE0, 0x00, 0x00, 0x0F, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00, // 'Þ'
+        0x00, 0xF0, 0x08, 0x88, 0x48, 0x30, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x08, 0x09, 0x09, 0x06, 0x00, // 'ß'
+        0x00, 0x00, 0x40, 0x48, 0x50, 0x40, 0x80, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0F, 0x00, // 'à'
+        0x00, 0x00, 0x40, 0x40, 0x50, 0x48, 0x80, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0F, 0x00, // 'á'
+        0x00, 0x00, 0x50, 0x48, 0x48, 0x50, 0x80, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0F, 0x00, // 'â'
+        0x00, 0x10, 0x48, 0x48, 0x50, 0x50, 0x88, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0F, 0x00, // 'ã'
+        0x00, 0x00, 0x58, 0x40, 0x40, 0x58, 0x80, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0F, 0x00, // 'ä'
+        0x00, 0x00, 0x48, 0x54, 0x54, 0x48, 0x80, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0F, 0x00, // 'å'
+        0x00, 0x40, 0x40, 0x80, 0x40, 0x40, 0x80, 0x00, 0x06, 0x09, 0x09, 0x07, 0x09, 0x09, 0x04, 0x00, // 'æ'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x08, 0x28, 0x18, 0x08, 0x04, 0x00, // 'ç'
+        0x00, 0x80, 0x40, 0x48, 0x50, 0x40, 0x80, 0x00, 0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00, // 'è'
+        0x00, 0x80, 0x40, 0x50, 0x48, 0x40, 0x80, 0x00, 0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00, // 'é'
+        0x00, 0x80, 0x50, 0x48, 0x48, 0x50, 0x80, 0x00, 0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00, // 'ê'
+        0x00, 0x80, 0x58, 0x40, 0x40, 0x58, 0x80, 0x00, 0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00, // 'ë'
+        0x00, 0x00, 0x48, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'ì'
+        0x00, 0x00, 0x50, 0xC8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'í'
+        0x00, 0x10, 0x48, 0xC8, 0x10, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'î'
+        0x00, 0x18, 0x40, 0xC0, 0x18, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x00, 0x00, // 'ï'
+        0x00, 0x80, 0x54, 0x48, 0x58, 0x64, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'ð'
+        0x00, 0xD0, 0x88, 0x48, 0x50, 0x50, 0x88, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, // 'ñ'
+        0x00, 0x80, 0x48, 0x50, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'ò'
+        0x00, 0x80, 0x40, 0x50, 0x48, 0x40, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'ó'
+        0x00, 0x80, 0x50, 0x48, 0x48, 0x50, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'ô'
+        0x00, 0x90, 0x48, 0x48, 0x50, 0x50, 0x88, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'õ'
+        0x00, 0x80, 0x58, 0x40, 0x40, 0x58, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00, // 'ö'
+        0x00, 0x80, 0x80, 0xB0, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, // '÷'
+        0x00, 0x80, 0x40, 0x40, 0x40, 0xC0, 0xA0, 0x00, 0x00, 0x17, 0x0C, 0x0A, 0x09, 0x08, 0x07, 0x00, // 'ø'
+        0x00, 0xC0, 0x08, 0x10, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00, // 'ù'
+        0x00, 0xC0, 0x00, 0x10, 0x08, 0xC0, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00, // 'ú'
+        0x00, 0xC0, 0x10, 0x08, 0x08, 0xD0, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00, // 'û'
+        0x00, 0xC0, 0x18, 0x00, 0x18, 0xC0, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00, // 'ü'
+        0x00, 0xC0, 0x00, 0x10, 0x08, 0x00, 0xC0, 0x00, 0x00, 0x13, 0x24, 0x24, 0x24, 0x22, 0x1F, 0x00, // 'ý'
+        0x00, 0xF0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x3F, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00, // 'þ'
+        0x00, 0xC0, 0x18, 0x00, 0x00, 0x18, 0xC0, 0x00, 0x00, 0x13, 0x24, 0x24, 0x24, 0x22, 0x1F, 0x00, // 'ÿ'
+        0xF0, 0x08, 0x08, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x09, 0x0A, 0x0E, 0x00, 0x0E, 0x0A, 0x09, 0x00, // '\u{3a9}'
+        0xF0, 0x08, 0x08, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x09, 0x0A, 0x0E, 0x00, 0x0E, 0x0A, 0x09, 0x00, // '\u{2126}'
+    ],
+    widths: &[],
+    kerning: &[],
+    replacement: Some('?'),
+};
+
+/// Proportional 5x7 font, ASCII, part of Latin-1 and Omega
+pub static FONT_5X7_PROP: Font = Font {
+    width: 5,
+    height: 7,
+    ranges: &[(' ', '~', 0), ('\u{a0}', '¿', 95), ('Ä', 'Ç', 127), ('Ð', 'Ñ', 131), ('Ö', 'Ø', 133), ('Ü', 'Ü', 136), ('Þ', 'ÿ', 137), ('\u{3a9}', '\u{3a9}', 171), ('\u{2126}', '\u{2126}', 172)],
+    data: &[
+        0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+        0x2F, 0x00, 0x00, 0x00, 0x00, // '!'
+        0x07, 0x00, 0x07, 0x00, 0x00, // '"'
+        0x14, 0x3E, 0x14, 0x3E, 0x14, // '#'
+        0x04, 0x2A, 0x3E, 0x2A, 0x10, // '$'
+        0x13, 0x08, 0x04, 0x32, 0x00, // '%'
+        0x14, 0x2A, 0x14, 0x20, 0x00, // '&'
+        0x07, 0x00, 0x00, 0x00, 0x00, // '\''
+        0x1E, 0x21, 0x00, 0x00, 0x00, // '('
+        0x21, 0x1E, 0x00, 0x00, 0x00, // ')'
+        0x2A, 0x1C, 0x2A, 0x00, 0x00, // '*'
+        0x08, 0x08, 0x3E, 0x08, 0x08, // '+'
+        0x40, 0x30, 0x10, 0x00, 0x00, // ','
+        0x08, 0x08, 0x08, 0x08, 0x00, // '-'
+        0x30, 0x30, 0x00, 0x00, 0x00, // '.'
+        0x10, 0x08, 0x04, 0x02, 0x00, // '/'
+        0x1E, 0x21, 0x1E, 0x00, 0x00, // '0'
+        0x22, 0x3F, 0x20, 0x00, 0x00, // '1'
+        0x22, 0x31, 0x29, 0x26, 0x00, // '2'
+        0x11, 0x25, 0x25, 0x1B, 0x00, // '3'
+        0x0C, 0x0A, 0x3F, 0x08, 0x00, // '4'
+        0x17, 0x25, 0x25, 0x19, 0x00, // '5'
+        0x1E, 0x25, 0x25, 0x18, 0x00, // '6'
+        0x01, 0x31, 0x0D, 0x03, 0x00, // '7'
+        0x1A, 0x25, 0x25, 0x1A, 0x00, // '8'
+        0x06, 0x29, 0x29, 0x1E, 0x00, // '9'
+        0x36, 0x36, 0x00, 0x00, 0x00, // ':'
+        0x40, 0x36, 0x16, 0x00, 0x00, // ';'
+        0x08, 0x14, 0x22, 0x00, 0x00, // '<'
+        0x14, 0x14, 0x14, 0x14, 0x00, // '='
+        0x22, 0x14, 0x08, 0x00, 0x00, // '>'
+        0x02, 0x29, 0x06, 0x00, 0x00, // '?'
+        0x1E, 0x21, 0x2D, 0x0E, 0x00, // '@'
+        0x3E, 0x09, 0x09, 0x3E, 0x00, // 'A'
+        0x3F, 0x25, 0x25, 0x1A, 0x00, // 'B'
+        0x1E, 0x21, 0x21, 0x12, 0x00, // 'C'
+        0x3F, 0x21, 0x21, 0x1E, 0x00, // 'D'
+        0x3F, 0x25, 0x25, 0x21, 0x00, // 'E'
+        0x3F, 0x05, 0x05, 0x01, 0x00, // 'F'
+        0x1E, 0x21, 0x29, 0x3A, 0x00, // 'G'
+        0x3F, 0x04, 0x04, 0x3F, 0x00, // 'H'
+        0x21, 0x3F, 0x21, 0x00, 0x00, // 'I'
+        0x10, 0x20, 0x20, 0x1F, 0x00, // 'J'
+        0x3F, 0x0C, 0x12, 0x21, 0x00, // 'K'
+        0x3F, 0x20, 0x20, 0x20, 0x00, // 'L'
+        0x3F, 0x06, 0x06, 0x3F, 0x00, // 'M'
+        0x3F, 0x06, 0x18, 0x3F, 0x00, // 'N'
+        0x1E, 0x21, 0x21, 0x1E, 0x00, // 'O'
+        0x3F, 0x09, 0x09, 0x06, 0x00, // 'P'
+        0x1E, 0x31, 0x21, 0x5E, 0x00, // 'Q'
+        0x3F, 0x09, 0x19, 0x26, 0x00, // 'R'
+        0x12, 0x25, 0x29, 0x12, 0x00, // 'S'
+        0x01, 0x3F, 0x01, 0x00, 0x00, // 'T'
+        0x1F, 0x20, 0x20, 0x1F, 0x00, // 'U'
+        0x0F, 0x30, 0x30, 0x0F, 0x00, // 'V'
+        0x3F, 0x18, 0x18, 0x3F, 0x00, // 'W'
+        0x33, 0x0C, 0x0C, 0x33, 0x00, // 'X'
+        0x07, 0x38, 0x07, 0x00, 0x00, // 'Y'
+        0x31, 0x29, 0x25, 0x23, 0x00, // 'Z'
+        0x3F, 0x21, 0x21, 0x00, 0x00, // '['
+        0x02, 0x04, 0x08, 0x10, 0x00, // '\\'
+        0x21, 0x21, 0x3F, 0x00, 0x00, // ']'
+        0x02, 0x01, 0x02, 0x00, 0x00, // '^'
+        0x20, 0x20, 0x20, 0x20, 0x00, // '_'
+        0x01, 0x02, 0x00, 0x00, 0x00, // '`'
+        0x18, 0x24, 0x14, 0x3C, 0x00, // 'a'
+        0x3F, 0x24, 0x24, 0x18, 0x00, // 'b'
+        0x18, 0x24, 0x24, 0x00, 0x00, // 'c'
+        0x18, 0x24, 0x24, 0x3F, 0x00, // 'd'
+        0x18, 0x34, 0x2C, 0x08, 0x00, // 'e'
+        0x08, 0x3E, 0x09, 0x02, 0x00, // 'f'
+        0x28, 0x54, 0x54, 0x4C, 0x00, // 'g'
+        0x3F, 0x04, 0x04, 0x38, 0x00, // 'h'
+        0x24, 0x3D, 0x20, 0x00, 0x00, // 'i'
+        0x20, 0x40, 0x3D, 0x00, 0x00, // 'j'
+        0x3F, 0x08, 0x14, 0x20, 0x00, // 'k'
+        0x21, 0x3F, 0x20, 0x00, 0x00, // 'l'
+        0x3C, 0x08, 0x0C, 0x38, 0x00, // 'm'
+        0x3C, 0x04, 0x04, 0x38, 0x00, // 'n'
+        0x18, 0x24, 0x24, 0x18, 0x00, // 'o'
+        0x7C, 0x24, 0x24, 0x18, 0x00, // 'p'
+        0x18, 0x24, 0x24, 0x7C, 0x00, // 'q'
+        0x3C, 0x04, 0x04, 0x08, 0x00, // 'r'
+        0x28, 0x2C, 0x34, 0x14, 0x00, // 's'
+        0x04, 0x1F, 0x24, 0x20, 0x00, // 't'
+        0x1C, 0x20, 0x20, 0x3C, 0x00, // 'u'
+        0x1C, 0x20, 0x1C, 0x00, 0x00, // 'v'
+        0x3C, 0x30, 0x30, 0x3C, 0x00, // 'w'
+        0x24, 0x18, 0x18, 0x24, 0x00, // 'x'
+        0x0C, 0x50, 0x20, 0x1C, 0x00, // 'y'
+        0x24, 0x34, 0x2C, 0x24, 0x00, // 'z'
+        0x04, 0x1E, 0x21, 0x00, 0x00, // '{'
+        0x3F, 0x00, 0x00, 0x00, 0x00, // '|'
+        0x21, 0x1E, 0x04, 0x00, 0x00, // '}'
+        0x02, 0x01, 0x02, 0x01, 0x00, // '~'
+        0x00, 0x00, 0x00, 0x00, 0x00, // '\u{a0}'
+        0x3D, 0x00, 0x00, 0x00, 0x00, // '¡'
+        0x18, 0x24, 0x7E, 0x24, 0x00, // '¢'
+        0x28, 0x1C, 0x2A, 0x22, 0x00, // '£'
+        0x22, 0x1C, 0x14, 0x1C, 0x22, // '¤'
+        0x0B, 0x3C, 0x0B, 0x00, 0x00, // '¥'
+        0x36, 0x00, 0x00, 0x00, 0x00, // '¦'
+        0x4E, 0x55, 0x39, 0x00, 0x00, // '§'
+        0x01, 0x00, 0x01, 0x00, 0x00, // '¨'
+        0x3E, 0x49, 0x55, 0x41, 0x3E, // '©'
+        0x02, 0x05, 0x07, 0x00, 0x00, // 'ª'
+        0x08, 0x14, 0x00, 0x08, 0x14, // '«'
+        0x08, 0x08, 0x08, 0x18, 0x00, // '¬'
+        0x08, 0x08, 0x08, 0x00, 0x00, // '\u{ad}'
+        0x3E, 0x5D, 0x45, 0x41, 0x3E, // '®'
+        0x01, 0x01, 0x01, 0x01, 0x00, // '¯'
+        0x02, 0x05, 0x02, 0x00, 0x00, // '°'
+        0x24, 0x24, 0x3F, 0x24, 0x24, // '±'
+        0x0D, 0x0B, 0x00, 0x00, 0x00, // '²'
+        0x0B, 0x0F, 0x00, 0x00, 0x00, // '³'
+        0x02, 0x01, 0x00, 0x00, 0x00, // '´'
+        0x7C, 0x20, 0x20, 0x1C, 0x00, // 'µ'
+        0x06, 0x3F, 0x01, 0x3F, 0x00, // '¶'
+        0x0C, 0x0C, 0x00, 0x00, 0x00, // '·'
+        0x40, 0x20, 0x00, 0x00, 0x00, // '¸'
+        0x0A, 0x0F, 0x08, 0x00, 0x00, // '¹'
+        0x02, 0x05, 0x02, 0x00, 0x00, // 'º'
+        0x14, 0x08, 0x00, 0x14, 0x08, // '»'
+        0x0F, 0x20, 0x30, 0x78, 0x00, // '¼'
+        0x0F, 0x00, 0x68, 0x58, 0x00, // '½'
+        0x0B, 0x2F, 0x30, 0x78, 0x00, // '¾'
+        0x18, 0x25, 0x10, 0x00, 0x00, // '¿'
+        0x3D, 0x0A, 0x0A, 0x3D, 0x00, // 'Ä'
+        0x3C, 0x0B, 0x0B, 0x3C, 0x00, // 'Å'
+        0x3E, 0x09, 0x3F, 0x25, 0x00, // 'Æ'
+        0x1E, 0x61, 0x21, 0x12, 0x00, // 'Ç'
+        0x25, 0x3F, 0x21, 0x1E, 0x00, // 'Ð'
+        0x3F, 0x04, 0x19, 0x3F, 0x00, // 'Ñ'
+        0x1D, 0x22, 0x22, 0x1D, 0x00, // 'Ö'
+        0x24, 0x18, 0x18, 0x24, 0x00, // '×'
+        0x3E, 0x39, 0x27, 0x1F, 0x00, // 'Ø'
+        0x1D, 0x20, 0x20, 0x1D, 0x00, // 'Ü'
+        0x3F, 0x0A, 0x0A, 0x04, 0x00, // 'Þ'
+        0x3E, 0x01, 0x25, 0x1A, 0x00, // 'ß'
+        0x18, 0x25, 0x16, 0x3C, 0x00, // 'à'
+        0x18, 0x26, 0x15, 0x3C, 0x00, // 'á'
+        0x18, 0x26, 0x15, 0x3E, 0x00, // 'â'
+        0x1A, 0x25, 0x16, 0x3D, 0x00, // 'ã'
+        0x18, 0x25, 0x14, 0x3D, 0x00, // 'ä'
+        0x18, 0x27, 0x17, 0x3C, 0x00, // 'å'
+        0x18, 0x24, 0x3C, 0x2C, 0x00, // 'æ'
+        0x18, 0x64, 0x24, 0x00, 0x00, // 'ç'
+        0x18, 0x35, 0x2E, 0x08, 0x00, // 'è'
+        0x18, 0x36, 0x2D, 0x08, 0x00, // 'é'
+        0x1A, 0x35, 0x2E, 0x08, 0x00, // 'ê'
+        0x19, 0x34, 0x2D, 0x08, 0x00, // 'ë'
+        0x25, 0x3E, 0x20, 0x00, 0x00, // 'ì'
+        0x26, 0x3D, 0x20, 0x00, 0x00, // 'í'
+        0x26, 0x3D, 0x22, 0x00, 0x00, // 'î'
+        0x25, 0x3C, 0x21, 0x00, 0x00, // 'ï'
+        0x18, 0x25, 0x26, 0x1A, 0x00, // 'ð'
+        0x3E, 0x05, 0x06, 0x39, 0x00, // 'ñ'
+        0x18, 0x25, 0x26, 0x18, 0x00, // 'ò'
+        0x18, 0x26, 0x25, 0x18, 0x00, // 'ó'
+        0x18, 0x25, 0x25, 0x18, 0x00, // 'ô'
+        0x1A, 0x25, 0x26, 0x19, 0x00, // 'õ'
+        0x18, 0x25, 0x24, 0x19, 0x00, // 'ö'
+        0x08, 0x2A, 0x2A, 0x08, 0x00, // '÷'
+        0x38, 0x34, 0x2C, 0x1C, 0x00, // 'ø'
+        0x1C, 0x21, 0x22, 0x3C, 0x00, // 'ù'
+        0x1C, 0x22, 0x21, 0x3C, 0x00, // 'ú'
+        0x1C, 0x21, 0x21, 0x3C, 0x00, // 'û'
+        0x1C, 0x21, 0x20, 0x3D, 0x00, // 'ü'
+        0x0C, 0x52, 0x21, 0x1C, 0x00, // 'ý'
+        0x7E, 0x24, 0x24, 0x18, 0x00, // 'þ'
+        0x0C, 0x51, 0x20, 0x1D, 0x00, // 'ÿ'
+        0x2E, 0x11, 0x2E, 0x00, 0x00, // '\u{3a9}'
+        0x2E, 0x11, 0x2E, 0x00, 0x00, // '\u{2126}'
+    ],
+    widths: &[
+        3, 2, 4, 6, 6, 5, 5, 2, 3, 3, 4, 6, 4, 5, 3, 5,
+        4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 3, 4, 4, 5, 4, 4,
+        5, 5, 5, 5, 5, 5, 5, 5, 5, 4, 5, 5, 5, 5, 5, 5,
+        5, 5, 5, 5, 4, 5, 5, 5, 5, 4, 5, 4, 5, 4, 4, 5,
+        3, 5, 5, 4, 5, 5, 5, 5, 5, 4, 4, 5, 4, 5, 5, 5,
+        5, 5, 5, 5, 5, 5, 4, 5, 5, 5, 5, 4, 2, 4, 5, 3,
+        2, 5, 5, 6, 4, 2, 4, 4, 6, 4, 6, 5, 4, 6, 5, 4,
+        6, 3, 3, 3, 5, 5, 3, 3, 4, 4, 6, 5, 5, 5, 4, 5,
+        5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+        5, 5, 4, 5, 5, 5, 5, 4, 4, 4, 4, 5, 5, 5, 5, 5,
+        5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 4, 4,
+    ],
+    kerning: &[
+        ('"', ',', -1), ('"', '.', -1), ('"', 'J', -1), ('"', 'j', -1), ('\'', ',', -1), ('\'', '.', -1),
+        ('\'', 'J', -1), ('\'', 'j', -1), (',', '"', -1), (',', '\'', -1), ('-', 'j', -1), ('.', '"', -1),
+        ('.', '\'', -1), ('/', ',', -1), ('/', '.', -1), ('/', '/', -1), ('/', 'J', -1), ('/', 'j', -1),
+        ('F', ',', -1), ('F', '.', -1), ('F', '/', -1), ('F', 'J', -1), ('F', 'j', -1), ('L', '"', -1),
+        ('L', '\'', -1), ('L', '-', -1), ('P', ',', -1), ('P', 'j', -1), ('f', ',', -1),
+    ],
+    replacement: Some('?'),
+};
+
+/// Proportional 6x12 font, ASCII, Latin-1 and Omega
+pub static FONT_6X12_PROP: Font = Font {
+    width: 6,
+    height: 12,
+    ranges: &[(' ', '~', 0), ('\u{a0}', 'ÿ', 95), ('\u{3a9}', '\u{3a9}', 191), ('\u{2126}', '\u{2126}', 192)],
+    data: &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ' '
+        0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, // '!'
+        0x1C, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '"'
+        0x20, 0xF0, 0x20, 0xF0, 0x20, 0x00, 0x01, 0x03, 0x01, 0x03, 0x01, 0x00, // '#'
+        0x30, 0x48, 0xFC, 0x48, 0x90, 0x00, 0x01, 0x02, 0x07, 0x02, 0x01, 0x00, // '$'
+        0x18, 0x98, 0x40, 0x20, 0x18, 0x00, 0x03, 0x00, 0x00, 0x03, 0x03, 0x00, // '%'
+        0xB0, 0x48, 0xB0, 0x00, 0x80, 0x00, 0x01, 0x02, 0x02, 0x01, 0x02, 0x00, // '&'
+        0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\''
+        0xE0, 0x18, 0x04, 0x00, 0x00, 0x00, 0x00, 0x03, 0x04, 0x00, 0x00, 0x00, // '('
+        0x04, 0x18, 0xE0, 0x00, 0x00, 0x00, 0x04, 0x03, 0x00, 0x00, 0x00, 0x00, // ')'
+        0x10, 0xA0, 0xF8, 0xA0, 0x10, 0x00, 0x01, 0x00, 0x03, 0x00, 0x01, 0x00, // '*'
+        0x40, 0x40, 0xF0, 0x40, 0x40, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // '+'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x03, 0x00, 0x00, 0x00, // ','
+        0x40, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '-'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00, 0x00, // '.'
+        0x00, 0x80, 0x40, 0x30, 0x08, 0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, // '/'
+        0xF0, 0x08, 0x08, 0xF0, 0x00, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00, 0x00, // '0'
+        0x10, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // '1'
+        0x10, 0x08, 0x88, 0x48, 0x30, 0x00, 0x02, 0x03, 0x02, 0x02, 0x02, 0x00, // '2'
+        0x08, 0x08, 0x48, 0x68, 0x98, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '3'
+        0xC0, 0xA0, 0x90, 0xF8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, // '4'
+        0x38, 0x28, 0x28, 0x28, 0xC8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '5'
+        0xE0, 0x50, 0x48, 0x48, 0x80, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '6'
+        0x08, 0x08, 0x88, 0x68, 0x18, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // '7'
+        0xB0, 0x48, 0x48, 0x48, 0xB0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '8'
+        0x30, 0x48, 0x48, 0x48, 0xF0, 0x00, 0x00, 0x02, 0x02, 0x01, 0x00, 0x00, // '9'
+        0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00, 0x00, // ':'
+        0x00, 0x60, 0x60, 0x00, 0x00, 0x00, 0x04, 0x07, 0x03, 0x00, 0x00, 0x00, // ';'
+        0x40, 0xA0, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // '<'
+        0xA0, 0xA0, 0xA0, 0xA0, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '='
+        0x10, 0xA0, 0x40, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // '>'
+        0x10, 0x08, 0xC8, 0x28, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, // '?'
+        0xF0, 0x08, 0xE8, 0xA8, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // '@'
+        0xF0, 0x48, 0x48, 0x48, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'A'
+        0x08, 0xF8, 0x48, 0x48, 0xB0, 0x00, 0x02, 0x03, 0x02, 0x02, 0x01, 0x00, // 'B'
+        0xF0, 0x08, 0x08, 0x08, 0x10, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'C'
+        0x08, 0xF8, 0x08, 0x08, 0xF0, 0x00, 0x02, 0x03, 0x02, 0x02, 0x01, 0x00, // 'D'
+        0xF8, 0x48, 0x48, 0x48, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'E'
+        0xF8, 0x48, 0x48, 0x48, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // 'F'
+        0xF0, 0x08, 0x08, 0x88, 0x90, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'G'
+        0xF8, 0x40, 0x40, 0x40, 0xF8, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'H'
+        0x08, 0xF8, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'I'
+        0x00, 0x00, 0x08, 0xF8, 0x08, 0x00, 0x01, 0x02, 0x02, 0x01, 0x00, 0x00, // 'J'
+        0xF8, 0x40, 0xA0, 0x10, 0x08, 0x00, 0x03, 0x00, 0x00, 0x01, 0x02, 0x00, // 'K'
+        0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'L'
+        0xF8, 0x10, 0x20, 0x10, 0xF8, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'M'
+        0xF8, 0x20, 0x40, 0x80, 0xF8, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'N'
+        0xF0, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'O'
+        0xF8, 0x48, 0x48, 0x48, 0x30, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // 'P'
+        0xF0, 0x08, 0x88, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x01, 0x02, 0x00, // 'Q'
+        0xF8, 0x48, 0xC8, 0x48, 0x30, 0x00, 0x03, 0x00, 0x00, 0x01, 0x02, 0x00, // 'R'
+        0x30, 0x48, 0x48, 0x48, 0x90, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'S'
+        0x08, 0x08, 0xF8, 0x08, 0x08, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // 'T'
+        0xF8, 0x00, 0x00, 0x00, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'U'
+        0x78, 0x80, 0x00, 0x80, 0x78, 0x00, 0x00, 0x01, 0x02, 0x01, 0x00, 0x00, // 'V'
+        0xF8, 0x00, 0x80, 0x00, 0xF8, 0x00, 0x01, 0x02, 0x01, 0x02, 0x01, 0x00, // 'W'
+        0x18, 0xA0, 0x40, 0xA0, 0x18, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'X'
+        0x18, 0x20, 0xC0, 0x20, 0x18, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // 'Y'
+        0x08, 0x88, 0x48, 0x28, 0x18, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'Z'
+        0xFC, 0x04, 0x04, 0x00, 0x00, 0x00, 0x07, 0x04, 0x04, 0x00, 0x00, 0x00, // '['
+        0x08, 0x30, 0x40, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, // '\\'
+        0x04, 0x04, 0xFC, 0x00, 0x00, 0x00, 0x04, 0x04, 0x07, 0x00, 0x00, 0x00, // ']'
+        0x10, 0x08, 0x04, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '^'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, // '_'
+        0x04, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '`'
+        0x00, 0xA0, 0xA0, 0xA0, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'a'
+        0xF8, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x03, 0x02, 0x02, 0x02, 0x01, 0x00, // 'b'
+        0xC0, 0x20, 0x20, 0x20, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'c'
+        0xC0, 0x20, 0x20, 0x20, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'd'
+        0xC0, 0xA0, 0xA0, 0xA0, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'e'
+        0x40, 0xF0, 0x48, 0x08, 0x10, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, // 'f'
+        0xC0, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x01, 0x0A, 0x0A, 0x0A, 0x07, 0x00, // 'g'
+        0xF8, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'h'
+        0x20, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'i'
+        0x00, 0x00, 0x20, 0xE8, 0x00, 0x00, 0x04, 0x08, 0x08, 0x07, 0x00, 0x00, // 'j'
+        0xF8, 0x80, 0x80, 0x40, 0x20, 0x00, 0x03, 0x00, 0x00, 0x01, 0x02, 0x00, // 'k'
+        0x08, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'l'
+        0xE0, 0x20, 0xC0, 0x20, 0xC0, 0x00, 0x03, 0x00, 0x03, 0x00, 0x03, 0x00, // 'm'
+        0xE0, 0x40, 0x20, 0x20, 0xC0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'n'
+        0xC0, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'o'
+        0xE0, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x01, 0x00, // 'p'
+        0xC0, 0x20, 0x20, 0x20, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x0F, 0x00, // 'q'
+        0xE0, 0x40, 0x20, 0x20, 0x40, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // 'r'
+        0x40, 0xA0, 0xA0, 0xA0, 0x20, 0x00, 0x02, 0x02, 0x02, 0x02, 0x01, 0x00, // 's'
+        0x20, 0x20, 0xF8, 0x20, 0x20, 0x00, 0x00, 0x00, 0x01, 0x02, 0x02, 0x00, // 't'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x01, 0x03, 0x00, // 'u'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x00, 0x01, 0x02, 0x01, 0x00, 0x00, // 'v'
+        0xE0, 0x00, 0x80, 0x00, 0xE0, 0x00, 0x01, 0x02, 0x01, 0x02, 0x01, 0x00, // 'w'
+        0x20, 0x40, 0x80, 0x40, 0x20, 0x00, 0x02, 0x01, 0x00, 0x01, 0x02, 0x00, // 'x'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x08, 0x05, 0x02, 0x01, 0x00, 0x00, // 'y'
+        0x20, 0x20, 0xA0, 0x60, 0x20, 0x00, 0x02, 0x03, 0x02, 0x02, 0x02, 0x00, // 'z'
+        0x40, 0xB8, 0x04, 0x00, 0x00, 0x00, 0x00, 0x03, 0x04, 0x00, 0x00, 0x00, // '{'
+        0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, // '|'
+        0x04, 0xB8, 0x40, 0x00, 0x00, 0x00, 0x04, 0x03, 0x00, 0x00, 0x00, 0x00, // '}'
+        0xC0, 0x20, 0x40, 0x80, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '~'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\u{a0}'
+        0xE8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // '¡'
+        0xC0, 0x20, 0xF0, 0x20, 0x40, 0x00, 0x01, 0x02, 0x07, 0x02, 0x01, 0x00, // '¢'
+        0x40, 0xF0, 0x48, 0x08, 0x10, 0x00, 0x02, 0x01, 0x02, 0x02, 0x01, 0x00, // '£'
+        0xA0, 0x40, 0x20, 0x40, 0xA0, 0x00, 0x02, 0x01, 0x02, 0x01, 0x02, 0x00, // '¤'
+        0xA8, 0xB0, 0xE0, 0xB0, 0xA8, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // '¥'
+        0xB8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // '¦'
+        0x68, 0x94, 0x94, 0x64, 0x00, 0x00, 0x02, 0x02, 0x02, 0x01, 0x00, 0x00, // '§'
+        0x04, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¨'
+        0xF0, 0x08, 0x48, 0xA8, 0x08, 0xF0, 0x01, 0x02, 0x02, 0x02, 0x02, 0x01, // '©'
+        0x24, 0x2A, 0x2E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 'ª'
+        0x80, 0x40, 0xA0, 0x40, 0x20, 0x00, 0x00, 0x01, 0x02, 0x01, 0x02, 0x00, // '«'
+        0x40, 0x40, 0x40, 0x40, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, // '¬'
+        0x40, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '\u{ad}'
+        0xF0, 0x08, 0xE8, 0x28, 0x08, 0xF0, 0x01, 0x02, 0x02, 0x02, 0x02, 0x01, // '®'
+        0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¯'
+        0x0C, 0x12, 0x12, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '°'
+        0x20, 0x20, 0xF8, 0x20, 0x20, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x00, // '±'
+        0x12, 0x19, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '²'
+        0x11, 0x15, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '³'
+        0x10, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '´'
+        0xE0, 0x00, 0x00, 0x00, 0xE0, 0x00, 0x0F, 0x02, 0x02, 0x01, 0x03, 0x00, // 'µ'
+        0x38, 0x7C, 0xFC, 0x04, 0xFC, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x00, // '¶'
+        0x60, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '·'
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x04, 0x00, 0x00, 0x00, // '¸'
+        0x12, 0x1F, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // '¹'
+        0x12, 0x15, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 'º'
+        0x20, 0x40, 0xA0, 0x40, 0x80, 0x00, 0x02, 0x01, 0x02, 0x01, 0x00, 0x00, // '»'
+        0x02, 0xDF, 0xA0, 0xF0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, // '¼'
+        0x02, 0x1F, 0x20, 0x90, 0x60, 0x00, 0x00, 0x00, 0x03, 0x02, 0x02, 0x00, // '½'
+        0x11, 0xD5, 0xAA, 0xF0, 0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, // '¾'
+        0x00, 0x80, 0x68, 0x00, 0x00, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // '¿'
+        0xF0, 0x49, 0x4A, 0x48, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'À'
+        0xF0, 0x48, 0x4A, 0x49, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Á'
+        0xF0, 0x4A, 0x49, 0x4A, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Â'
+        0xF2, 0x49, 0x4B, 0x4A, 0xF1, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Ã'
+        0xF0, 0x4A, 0x48, 0x4A, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Ä'
+        0xF0, 0x4A, 0x4D, 0x4A, 0xF0, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Å'
+        0xF0, 0x48, 0xF8, 0x48, 0x08, 0x00, 0x03, 0x00, 0x03, 0x02, 0x02, 0x00, // 'Æ'
+        0xF0, 0x08, 0x08, 0x08, 0x10, 0x00, 0x01, 0x0A, 0x0A, 0x06, 0x01, 0x00, // 'Ç'
+        0xF8, 0x49, 0x4A, 0x48, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'È'
+        0xF8, 0x48, 0x4A, 0x49, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'É'
+        0xF8, 0x4A, 0x49, 0x4A, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'Ê'
+        0xF8, 0x4A, 0x48, 0x4A, 0x08, 0x00, 0x03, 0x02, 0x02, 0x02, 0x02, 0x00, // 'Ë'
+        0x09, 0xFA, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'Ì'
+        0x08, 0xFA, 0x09, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'Í'
+        0x0A, 0xF9, 0x0A, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'Î'
+        0x0A, 0xF8, 0x0A, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'Ï'
+        0x40, 0xF8, 0x48, 0x08, 0xF0, 0x00, 0x00, 0x03, 0x02, 0x02, 0x01, 0x00, // 'Ð'
+        0xFA, 0x21, 0x43, 0x82, 0xF9, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'Ñ'
+        0xF0, 0x09, 0x0A, 0x08, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ò'
+        0xF0, 0x08, 0x0A, 0x09, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ó'
+        0xF0, 0x0A, 0x09, 0x0A, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ô'
+        0xF2, 0x09, 0x0B, 0x0A, 0xF1, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Õ'
+        0xF0, 0x0A, 0x08, 0x0A, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ö'
+        0x10, 0xA0, 0x40, 0xA0, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, // '×'
+        0xF0, 0x08, 0xE8, 0x18, 0xF4, 0x00, 0x05, 0x03, 0x02, 0x02, 0x01, 0x00, // 'Ø'
+        0xF8, 0x01, 0x02, 0x00, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ù'
+        0xF8, 0x00, 0x02, 0x01, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ú'
+        0xF8, 0x02, 0x01, 0x02, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Û'
+        0xF8, 0x02, 0x00, 0x02, 0xF8, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'Ü'
+        0x18, 0x20, 0xC2, 0x21, 0x18, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, // 'Ý'
+        0xF8, 0x10, 0x10, 0xE0, 0x00, 0x00, 0x03, 0x01, 0x01, 0x00, 0x00, 0x00, // 'Þ'
+        0xF0, 0x08, 0x48, 0xA8, 0x10, 0x00, 0x03, 0x00, 0x02, 0x02, 0x01, 0x00, // 'ß'
+        0x00, 0xA4, 0xA8, 0xA0, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'à'
+        0x00, 0xA0, 0xA8, 0xA4, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'á'
+        0x00, 0xA8, 0xA4, 0xA8, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'â'
+        0x08, 0xA4, 0xAC, 0xA8, 0xC4, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'ã'
+        0x00, 0xA8, 0xA0, 0xA8, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'ä'
+        0x00, 0xA8, 0xB4, 0xA8, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x03, 0x00, // 'å'
+        0x00, 0xA0, 0xE0, 0xA0, 0x40, 0x00, 0x01, 0x02, 0x03, 0x02, 0x02, 0x00, // 'æ'
+        0xC0, 0x20, 0x20, 0x20, 0x40, 0x00, 0x01, 0x0A, 0x0A, 0x06, 0x01, 0x00, // 'ç'
+        0xC0, 0xA4, 0xA8, 0xA0, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'è'
+        0xC0, 0xA0, 0xA8, 0xA4, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'é'
+        0xC0, 0xA8, 0xA4, 0xA8, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'ê'
+        0xC0, 0xA8, 0xA0, 0xA8, 0x40, 0x00, 0x01, 0x02, 0x02, 0x02, 0x00, 0x00, // 'ë'
+        0x24, 0xE8, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'ì'
+        0x20, 0xE8, 0x04, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'í'
+        0x28, 0xE4, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'î'
+        0x28, 0xE0, 0x08, 0x00, 0x00, 0x00, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, // 'ï'
+        0xC0, 0x2A, 0x24, 0x2A, 0xF0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ð'
+        0xE8, 0x44, 0x2C, 0x28, 0xC4, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, // 'ñ'
+        0xC0, 0x24, 0x28, 0x20, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ò'
+        0xC0, 0x20, 0x28, 0x24, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ó'
+        0xC0, 0x28, 0x24, 0x28, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ô'
+        0xC8, 0x24, 0x2C, 0x28, 0xC4, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'õ'
+        0xC0, 0x28, 0x20, 0x28, 0xC0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ö'
+        0x40, 0x40, 0x50, 0x40, 0x40, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // '÷'
+        0xC0, 0x20, 0xA0, 0x60, 0xE0, 0x00, 0x03, 0x03, 0x02, 0x02, 0x01, 0x00, // 'ø'
+        0xE0, 0x04, 0x08, 0x00, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ù'
+        0xE0, 0x00, 0x08, 0x04, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ú'
+        0xE0, 0x08, 0x04, 0x08, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'û'
+        0xE0, 0x08, 0x00, 0x08, 0xE0, 0x00, 0x01, 0x02, 0x02, 0x02, 0x01, 0x00, // 'ü'
+        0xE0, 0x00, 0x08, 0x04, 0xE0, 0x00, 0x08, 0x05, 0x02, 0x01, 0x00, 0x00, // 'ý'
+        0xF8, 0x20, 0x20, 0x20, 0xC0, 0x00, 0x0F, 0x02, 0x02, 0x02, 0x01, 0x00, // 'þ'
+        0xE0, 0x08, 0x00, 0x08, 0xE0, 0x00, 0x08, 0x05, 0x02, 0x01, 0x00, 0x00, // 'ÿ'
+        0xF0, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x02, 0x03, 0x00, 0x03, 0x02, 0x00, // '\u{3a9}'
+        0xF0, 0x08, 0x08, 0x08, 0xF0, 0x00, 0x02, 0x03, 0x00, 0x03, 0x02, 0x00, // '\u{2126}'
+    ],
+    widths: &[
+        4, 2, 4, 6, 6, 6, 6, 2, 4, 4, 6, 6, 4, 6, 3, 6,
+        5, 4, 6, 6, 6, 6, 6, 6, 6, 6, 3, 4, 4, 6, 4, 6,
+        6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 6, 6, 6, 6, 6, 6,
+        6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 6, 4, 6, 6,
+        4, 6, 6, 6, 6, 6, 6, 6, 6, 4, 5, 6, 4, 6, 6, 6,
+        6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 2, 4, 6, 4,
+        2, 6, 6, 6, 6, 2, 5, 4, 7, 4, 6, 6, 4, 7, 6, 5,
+        6, 4, 4, 4, 6, 6, 3, 4, 4, 4, 6, 6, 6, 6, 6, 6,
+        6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 4, 4, 4, 6,
+        6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 5, 6, 6,
+        6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 4, 4, 4, 6,
+        6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+        6,
+    ],
+    kerning: &[
+        ('"', ',', -1), ('"', '-', -1), ('"', '.', -1), ('"', '/', -1), ('"', 'J', -1), ('"', 'j', -1),
+        ('\'', ',', -1), ('\'', '-', -1), ('\'', '.', -1), ('\'', '/', -1), ('\'', 'J', -1), ('\'', 'j', -1),
+        (',', '"', -1), (',', '\'', -1), (',', '-', -1), (',', 'T', -1), (',', 'Y', -1), (',', 't', -1),
+        ('-', '"', -1), ('-', '\'', -1), ('-', ',', -1), ('-', '.', -1), ('-', 'J', -1), ('-', 'T', -1),
+        ('-', 'j', -1), ('.', '"', -1), ('.', '\'', -1), ('.', '-', -1), ('.', 'T', -1), ('.', 'Y', -1),
+        ('.', 't', -1), ('/', ',', -1), ('/', '.', -1), ('/', '/', -1), ('/', 'J', -1), ('/', 'a', -1),
+        ('/', 'j', -1), ('C', '-', -1), ('F', ',', -1), ('F', '.', -1), ('F', '/', -1), ('F', 'J', -1),
+        ('F', 'a', -1), ('F', 'j', -1), ('J', ',', -1), ('J', 'j', -1), ('K', '-', -1), ('L', '"', -1),
+        ('L', '\'', -1), ('L', '-', -1), ('L', 'T', -1), ('L', 'Y', -1), ('L', 't', -1), ('P', ',', -1),
+        ('P', '.', -1), ('P', '/', -1), ('P', 'J', -1), ('P', 'j', -1), ('T', ',', -1), ('T', '-', -1),
+        ('T', '.', -1), ('T', '/', -1), ('T', 'J', -1), ('T', 'a', -1), ('T', 'c', -1), ('T', 'd', -1),
+        ('T', 'e', -1), ('T', 'g', -1), ('T', 'j', -1), ('T', 'm', -1), ('T', 'n', -1), ('T', 'o', -1),
+        ('T', 'p', -1), ('T', 'q', -1), ('T', 'r', -1), ('T', 's', -1), ('T', 't', -1), ('T', 'u', -1),
+        ('T', 'v', -1), ('T', 'w', -1), ('T', 'x', -1), ('T', 'y', -1), ('T', 'z', -1), ('V', ',', -1),
+        ('V', 'j', -1), ('Y', ',', -1), ('Y', '.', -1), ('Y', '/', -1), ('Y', 'J', -1), ('Y', 'j', -1),
+        ('a', 'T', -1), ('b', 'T', -1), ('c', 'T', -1), ('e', 'T', -1), ('f', ',', -1), ('f', '-', -1),
+        ('f', '.', -1), ('f', '/', -1), ('f', 'J', -1), ('g', 'T', -1), ('h', 'T', -1), ('k', 'T', -1),
+        ('m', 'T', -1), ('n', 'T', -1), ('o', 'T', -1), ('p', 'T', -1), ('q', 'T', -1), ('r', ',', -1),
+        ('r', '.', -1), ('r', 'J', -1), ('r', 'T', -1), ('s', 'T', -1), ('t', 'T', -1), ('u', 'T', -1),
+        ('v', 'T', -1), ('w', 'T', -1), ('x', 'T', -1), ('y', 'T', -1), ('z', 'T', -1),
+    ],
+    replacement: Some('?'),
+};
@@ -0,0 +1,173 @@
+use hal::blocking::i2c;
+
+use cmd::OutOfRange;
+use prelude::Write;
+use {Error, Ssd1306, BUF_SIZE};
+
+/// Grayscale framebuffer of 2 or 4 bit planes, shown by cycling through
+/// the planes with [`Ssd1306::refresh_plane`](../struct.Ssd1306.html#method.refresh_plane).
+/// Plane `n` holds bit `n` of each pixel's level and is shown for `2^n`
+/// refreshes per cycle.
+pub struct GrayBuffer<'a> {
+    planes: &'a mut [u8],
+    count: u8,
+    slot: u8,
+}
+
+impl<'a> GrayBuffer<'a> {
+    /// Create grayscale buffer in `storage`, which must hold 2 or 4 planes of 1024 bytes
+    pub fn new(storage: &'a mut [u8]) -> Result<GrayBuffer<'a>, OutOfRange> {
+        let count = match storage.len() {
+            len if len == 2 * BUF_SIZE => 2,
+            len if len == 4 * BUF_SIZE => 4,
+            _ => return Err(OutOfRange),
+        };
+
+        Ok(GrayBuffer {
+            planes: storage,
+            count,
+            slot: 1,
+        })
+    }
+
+    /// Number of gray levels, 4 or 16
+    pub fn levels(&self) -> u8 {
+        1 << self.count
+    }
+
+    /// Clear all planes
+    pub fn clear(&mut self) {
+        for byte in self.planes.iter_mut() {
+            *byte = 0;
+        }
+    }
+
+    /// Set pixel gray level. Levels above the brightest are clamped.
+    pub fn set_pixel(&mut self, x: u8, y: u8, level: u8) {
+        let level = level.min(self.levels() - 1);
+        let idx = ((y as usize) / 8 * 128) + (x as usize);
+        let bit = 1 << (y % 8);
+        for plane in 0..self.count {
+            let byte = &mut self.planes[plane as usize * BUF_SIZE + idx];
+            if level & (1 << plane) != 0 {
+                *byte |= bit;
+            } else {
+                *byte &= !bit;
+            }
+        }
+    }
+
+    /// Get pixel gray level
+    pub fn get_pixel(&self, x: u8, y: u8) -> u8 {
+        let idx = ((y as usize) / 8 * 128) + (x as usize);
+        let bit = 1 << (y % 8);
+        (0..self.count).fold(0, |level, plane| {
+            if self.planes[plane as usize * BUF_SIZE + idx] & bit != 0 {
+                level | (1 << plane)
+            } else {
+                level
+            }
+        })
+    }
+
+    /// Plane shown by the next refresh. Slots are numbered from 1 to
+    /// `2^count - 1`, and the number of trailing zeros in the slot picks
+    /// the plane, spreading each plane's refreshes evenly over the cycle.
+    pub fn current_plane(&self) -> u8 {
+        self.count - 1 - self.slot.trailing_zeros() as u8
+    }
+
+    fn plane(&self, plane: u8) -> &[u8] {
+        let start = plane as usize * BUF_SIZE;
+        &self.planes[start..start + BUF_SIZE]
+    }
+
+    fn advance(&mut self) {
+        self.slot += 1;
+        if self.slot == self.levels() {
+            self.slot = 1;
+        }
+    }
+}
+
+impl<I2C> Ssd1306<I2C>
+where
+    I2C: i2c::Write,
+{
+    /// Show the next plane of a grayscale buffer. Call at a steady rate, usually
+    /// from a timer. The monochrome buffer is redrawn in full by the next
+    /// [`draw`](#method.draw).
+    pub fn refresh_plane(&mut self, gray: &mut GrayBuffer) -> Result<(), Error<I2C::Error>> {
+        self.pause_scroll(|disp| {
+            let plane = gray.plane(gray.current_plane());
+            let width = disp.width as usize;
+            let pages = disp.page_mask();
+            disp.write_pages(pages, |disp, page| {
+                let start = page as usize * 128;
+                disp.i2c
+                    .write_data(disp.addr, &plane[start..start + width])
+                    .map_err(Error::Comm)
+            })
+        })?;
+        gray.advance();
+        self.dirty = 0xFF;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Resolution;
+
+    /// Keeps the last data byte written
+    struct Mock {
+        last: u8,
+    }
+
+    impl i2c::Write for Mock {
+        type Error = ();
+
+        fn write(&mut self, _addr: u8, bytes: &[u8]) -> Result<(), ()> {
+            if bytes[0] == 0x40 {
+                self.last = bytes[bytes.len() - 1];
+            }
+            Ok(())
+        }
+    }
+
+    /// Planes shown by the refreshes of two cycles, with every byte of plane `n` set to `n`
+    fn sequence(storage: &mut [u8], shown: &mut [u8]) {
+        for (i, byte) in storage.iter_mut().enumerate() {
+            *byte = (i / BUF_SIZE) as u8;
+        }
+        let mut gray = GrayBuffer::new(storage).unwrap();
+        let mut disp = Ssd1306::new(Mock { last: 0xFF }, 0x3C, Resolution::R128x64, true);
+        for plane in shown.iter_mut() {
+            let current = gray.current_plane();
+            disp.refresh_plane(&mut gray).unwrap();
+            *plane = disp.i2c.last;
+            assert_eq!(*plane, current);
+        }
+    }
+
+    #[test]
+    fn two_planes() {
+        let mut shown = [0; 6];
+        sequence(&mut [0; 2 * BUF_SIZE], &mut shown);
+        assert_eq!(shown, [1, 0, 1, 1, 0, 1]);
+    }
+
+    #[test]
+    fn four_planes() {
+        let mut shown = [0; 30];
+        sequence(&mut [0; 4 * BUF_SIZE], &mut shown);
+        for cycle in shown.chunks(15) {
+            for plane in 0..4 {
+                let count = cycle.iter().filter(|&&p| p == plane).count();
+                assert_eq!(count, 1 << plane);
+            }
+        }
+        assert_eq!(shown[..15], [3, 2, 3, 1, 3, 2, 3, 0, 3, 2, 3, 1, 3, 2, 3]);
+    }
+}
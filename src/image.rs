@@ -0,0 +1,386 @@
+use canvas::{Canvas, Color};
+
+/// Why an image could not be loaded
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageError {
+    /// Data is not an image of the expected format
+    Invalid,
+    /// Image uses a variant of the format that is not supported, like a compressed or color BMP
+    Unsupported,
+    /// Data ends before the image does
+    Truncated,
+}
+
+/// How an image stores its pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    /// Binary PBM, rows of bytes with the left pixel in the highest bit
+    Pbm,
+    /// ASCII PBM, a digit per pixel
+    PbmAscii,
+    /// XBM, C source of row bytes with the left pixel in the lowest bit
+    Xbm,
+    /// BMP, rows of bytes padded to 4 bytes, possibly stored bottom row first
+    Bmp {
+        bottom_up: bool,
+        /// Palette index of the darker color
+        ink: u8,
+    },
+}
+
+/// 1-bit image in PBM, XBM or BMP format. Pixels that are black in the file,
+/// or in the foreground for XBM, are ink.
+#[derive(Clone, Copy, Debug)]
+pub struct Image<'a> {
+    width: u16,
+    height: u16,
+    format: Format,
+    /// Pixel data
+    data: &'a [u8],
+}
+
+impl<'a> Image<'a> {
+    /// Load a binary (P4) or ASCII (P1) PBM image
+    pub fn pbm(data: &'a [u8]) -> Result<Image<'a>, ImageError> {
+        let mut pos = 0;
+        let format = match token(data, &mut pos) {
+            Some(b"P4") => Format::Pbm,
+            Some(b"P1") => Format::PbmAscii,
+            Some(b"P2") | Some(b"P3") | Some(b"P5") | Some(b"P6") => return Err(ImageError::Unsupported),
+            _ => return Err(ImageError::Invalid),
+        };
+        let width = token(data, &mut pos).and_then(decimal).ok_or(ImageError::Invalid)?;
+        let height = token(data, &mut pos).and_then(decimal).ok_or(ImageError::Invalid)?;
+
+        // A single whitespace character separates the header from binary data
+        let data = data.get(pos + 1..).unwrap_or(&[]);
+        let image = Image { width, height, format, data };
+        if format == Format::Pbm && data.len() < image.stride() * height as usize {
+            return Err(ImageError::Truncated);
+        }
+        Ok(image)
+    }
+
+    /// Load an XBM image
+    pub fn xbm(data: &'a [u8]) -> Result<Image<'a>, ImageError> {
+        let define = |name: &[u8]| {
+            let mut pos = find(data, name)? + name.len();
+            token(data, &mut pos).and_then(decimal)
+        };
+        let width = define(b"_width").ok_or(ImageError::Invalid)?;
+        let height = define(b"_height").ok_or(ImageError::Invalid)?;
+        let start = find(data, b"{").ok_or(ImageError::Invalid)?;
+        Ok(Image {
+            width,
+            height,
+            format: Format::Xbm,
+            data: &data[start + 1..],
+        })
+    }
+
+    /// Load an uncompressed 1 bit per pixel BMP image
+    pub fn bmp(data: &'a [u8]) -> Result<Image<'a>, ImageError> {
+        if data.len() < 54 || &data[..2] != b"BM" {
+            return Err(ImageError::Invalid);
+        }
+        let offset = le(data, 10, 4) as usize;
+        let header = le(data, 14, 4) as usize;
+        if header < 40 {
+            return Err(ImageError::Unsupported);
+        }
+        let width = le(data, 18, 4) as i32;
+        let height = le(data, 22, 4) as i32;
+        if le(data, 28, 2) != 1 || le(data, 30, 4) != 0 {
+            return Err(ImageError::Unsupported);
+        }
+        if width <= 0 || width > 0xFFFF || height == 0 || height.unsigned_abs() > 0xFFFF {
+            return Err(ImageError::Invalid);
+        }
+
+        // The darker palette color is ink
+        let palette = 14 + header;
+        let luma = |i: usize| {
+            let c = data.get(palette + i * 4..palette + i * 4 + 3).unwrap_or(&[0, 0, 0]);
+            c[0] as u32 * 29 + c[1] as u32 * 150 + c[2] as u32 * 77
+        };
+        let ink = if luma(1) < luma(0) { 1 } else { 0 };
+
+        let image = Image {
+            width: width as u16,
+            height: height.unsigned_abs() as u16,
+            format: Format::Bmp {
+                bottom_up: height > 0,
+                ink,
+            },
+            data: data.get(offset..).unwrap_or(&[]),
+        };
+        if image.data.len() < image.stride() * image.height as usize {
+            return Err(ImageError::Truncated);
+        }
+        Ok(image)
+    }
+
+    /// Width and height in pixels
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Pixels in rows from the top, with whether each is ink
+    pub fn pixels(&self) -> Pixels<'a> {
+        Pixels {
+            image: *self,
+            x: 0,
+            y: 0,
+            pos: 0,
+            byte: 0,
+        }
+    }
+
+    /// Bytes per row of binary formats
+    fn stride(&self) -> usize {
+        let bytes = (self.width as usize + 7) >> 3;
+        match self.format {
+            Format::Bmp { .. } => (bytes + 3) & !3,
+            _ => bytes,
+        }
+    }
+}
+
+/// Pixels of an image, decoded as they are read
+pub struct Pixels<'a> {
+    image: Image<'a>,
+    x: u16,
+    y: u16,
+    /// Position in text formats
+    pos: usize,
+    /// Current byte of XBM images
+    byte: u8,
+}
+
+impl<'a> Iterator for Pixels<'a> {
+    type Item = (u16, u16, bool);
+
+    fn next(&mut self) -> Option<(u16, u16, bool)> {
+        let image = self.image;
+        if self.y >= image.height || image.width == 0 {
+            return None;
+        }
+        let (x, y) = (self.x, self.y);
+        let data = image.data;
+
+        let ink = match image.format {
+            Format::Pbm => data[y as usize * image.stride() + x as usize / 8] & (0x80 >> (x % 8)) != 0,
+            Format::Bmp { bottom_up, ink } => {
+                let row = if bottom_up { image.height - 1 - y } else { y };
+                let bit = data[row as usize * image.stride() + x as usize / 8] & (0x80 >> (x % 8)) != 0;
+                bit == (ink == 1)
+            }
+            Format::PbmAscii => loop {
+                match data.get(self.pos) {
+                    Some(b'0') => break false,
+                    Some(b'1') => break true,
+                    Some(b'#') => self.pos = skip_comment(data, self.pos),
+                    Some(_) => self.pos += 1,
+                    None => break false,
+                }
+            },
+            Format::Xbm => {
+                if x % 8 == 0 {
+                    self.byte = hex(data, &mut self.pos).unwrap_or(0);
+                }
+                self.byte & (1 << (x % 8)) != 0
+            }
+        };
+        if image.format == Format::PbmAscii {
+            self.pos += 1;
+        }
+
+        self.x += 1;
+        if self.x == image.width {
+            self.x = 0;
+            self.y += 1;
+        }
+        Some((x, y, ink))
+    }
+}
+
+/// Draw the ink of an image with its top left corner at `x`, `y`,
+/// leaving the other pixels untouched
+pub fn draw_image<C: Canvas>(c: &mut C, x: i32, y: i32, image: &Image, color: Color) {
+    for (px, py, ink) in image.pixels() {
+        if ink {
+            c.set_pixel(x + px as i32, y + py as i32, color);
+        }
+    }
+}
+
+/// Draw an image with its top left corner at `x`, `y`, turning ink on and the other pixels off
+pub fn draw_image_opaque<C: Canvas>(c: &mut C, x: i32, y: i32, image: &Image) {
+    for (px, py, ink) in image.pixels() {
+        let color = if ink { Color::On } else { Color::Off };
+        c.set_pixel(x + px as i32, y + py as i32, color);
+    }
+}
+
+/// Next whitespace separated header token, skipping comments
+fn token<'a>(data: &'a [u8], pos: &mut usize) -> Option<&'a [u8]> {
+    while let Some(&b) = data.get(*pos) {
+        match b {
+            b'#' => *pos = skip_comment(data, *pos),
+            b' ' | b'\t' | b'\r' | b'\n' => *pos += 1,
+            _ => break,
+        }
+    }
+    let start = *pos;
+    while data.get(*pos).is_some_and(|b| !b" \t\r\n#".contains(b)) {
+        *pos += 1;
+    }
+    if *pos > start {
+        Some(&data[start..*pos])
+    } else {
+        None
+    }
+}
+
+/// Position of the end of a comment line
+fn skip_comment(data: &[u8], pos: usize) -> usize {
+    data[pos..].iter().position(|&b| b == b'\n').map_or(data.len(), |n| pos + n)
+}
+
+/// Parse a decimal size
+fn decimal(token: &[u8]) -> Option<u16> {
+    let mut n: u16 = 0;
+    for &b in token {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        n = n.checked_mul(10)?.checked_add((b - b'0') as u16)?;
+    }
+    Some(n)
+}
+
+/// Next `0x` prefixed hex byte
+fn hex(data: &[u8], pos: &mut usize) -> Option<u8> {
+    let prefix = data[*pos..].windows(2).position(|w| w[0] == b'0' && w[1] | 0x20 == b'x')?;
+    let start = *pos + prefix + 2;
+    let mut n = 0u8;
+    *pos = start;
+    while let Some(d) = data.get(*pos).and_then(|&b| (b as char).to_digit(16)) {
+        n = n.wrapping_shl(4) | d as u8;
+        *pos += 1;
+    }
+    Some(n)
+}
+
+/// Position of the first occurrence of `pattern`
+fn find(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len()).position(|w| w == pattern)
+}
+
+/// Little endian number of `len` bytes at `pos`
+fn le(data: &[u8], pos: usize, len: usize) -> u32 {
+    data[pos..pos + len].iter().rev().fold(0, |n, &b| n << 8 | b as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ink of a 3x2 image as rows of bits, left pixel in the highest bit
+    fn rows(image: &Image) -> [u8; 2] {
+        let mut rows = [0; 2];
+        let mut count = 0;
+        for (x, y, ink) in image.pixels() {
+            assert_eq!((x, y), (count % 3, count / 3));
+            rows[y as usize] |= (ink as u8) << (2 - x);
+            count += 1;
+        }
+        assert_eq!(count, 6);
+        rows
+    }
+
+    /// 3x2 BMP with rows 101 and 010 from the top
+    fn bmp(top_down: bool, black_first: bool) -> [u8; 70] {
+        let mut file = [0; 70];
+        let mut put = |pos: usize, n: u32| {
+            for i in 0..4 {
+                file[pos + i] = (n >> (8 * i)) as u8;
+            }
+        };
+        put(2, 70);
+        put(10, 62);
+        put(14, 40);
+        put(18, 3);
+        put(22, if top_down { -2i32 as u32 } else { 2 });
+        put(26, 1 << 16 | 1);
+        put(if black_first { 58 } else { 54 }, 0xFFFFFF);
+        file[0] = b'B';
+        file[1] = b'M';
+        let (first, second) = if top_down { (62, 66) } else { (66, 62) };
+        let flip = if black_first { 0xFF } else { 0 };
+        file[first] = 0xA0 ^ flip;
+        file[second] = 0x40 ^ flip;
+        file
+    }
+
+    /// Loading every prefix and reading its pixels must not panic
+    fn prefixes<F: Fn(&[u8]) -> Result<Image, ImageError>>(data: &[u8], load: F) {
+        for len in 0..data.len() {
+            if let Ok(image) = load(&data[..len]) {
+                for _ in image.pixels() {}
+            }
+        }
+    }
+
+    #[test]
+    fn pbm() {
+        let image = Image::pbm(b"P4\n# comment\n3 2\n\xA0\x40").unwrap();
+        assert_eq!(image.size(), (3, 2));
+        assert_eq!(rows(&image), [0b101, 0b010]);
+        let image = Image::pbm(b"P1\n3 2\n1 0 1\n# comment\n010").unwrap();
+        assert_eq!(rows(&image), [0b101, 0b010]);
+    }
+
+    #[test]
+    fn xbm() {
+        let data = b"#define x_width 3\n#define x_height 2\nstatic char x_bits[] = { 0x05, 0X02 };";
+        let image = Image::xbm(data).unwrap();
+        assert_eq!(image.size(), (3, 2));
+        assert_eq!(rows(&image), [0b101, 0b010]);
+    }
+
+    #[test]
+    fn bmp_order_and_palette() {
+        for &top_down in &[false, true] {
+            for &black_first in &[false, true] {
+                let file = bmp(top_down, black_first);
+                let image = Image::bmp(&file).unwrap();
+                assert_eq!(image.size(), (3, 2));
+                assert_eq!(rows(&image), [0b101, 0b010]);
+            }
+        }
+    }
+
+    #[test]
+    fn bad_input() {
+        assert_eq!(Image::pbm(b"P4\n3 2\n\xA0").err(), Some(ImageError::Truncated));
+        assert_eq!(Image::pbm(b"P6\n3 2\n").err(), Some(ImageError::Unsupported));
+        assert_eq!(Image::pbm(b"GIF89a").err(), Some(ImageError::Invalid));
+        assert_eq!(Image::pbm(b"P4 3 99999 ").err(), Some(ImageError::Invalid));
+        assert_eq!(Image::xbm(b"static char x_bits[] = {").err(), Some(ImageError::Invalid));
+        let file = bmp(false, false);
+        assert_eq!(Image::bmp(&file[..66]).err(), Some(ImageError::Truncated));
+        assert_eq!(Image::bmp(&file[..40]).err(), Some(ImageError::Invalid));
+        let mut tall = file;
+        tall[22..26].copy_from_slice(&[0, 0, 0, 0x80]);
+        assert_eq!(Image::bmp(&tall).err(), Some(ImageError::Invalid));
+        let mut color = file;
+        color[28] = 24;
+        assert_eq!(Image::bmp(&color).err(), Some(ImageError::Unsupported));
+
+        prefixes(b"P4\n3 2\n\xA0\x40", |d| Image::pbm(d));
+        prefixes(b"P1\n3 2\n1 0 1\n010", |d| Image::pbm(d));
+        prefixes(b"#define x_width 3\n#define x_height 2\n{ 0x05, 0x02 };", |d| Image::xbm(d));
+        prefixes(&bmp(true, false), |d| Image::bmp(d));
+    }
+}
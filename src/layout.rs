@@ -0,0 +1,229 @@
+use core::cmp::max;
+
+use canvas::{Canvas, Color};
+use font::{draw_text, Font};
+
+/// Appended to truncated text
+const ELLIPSIS: &str = "...";
+
+/// Horizontal alignment of lines in a text box
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Align {
+    /// Align to the left edge
+    Left,
+    /// Center between the edges
+    Center,
+    /// Align to the right edge
+    Right,
+}
+
+/// Layout of text in a box, breaking it into lines
+#[derive(Clone, Copy)]
+pub struct TextBox<'a> {
+    font: &'a Font,
+    width: i32,
+    height: i32,
+    align: Align,
+    wrap: bool,
+    ellipsis: bool,
+    line_spacing: i32,
+}
+
+impl<'a> TextBox<'a> {
+    /// Box of `width` by `height` pixels for text in `font`. Text is left
+    /// aligned, wrapped at spaces, and truncated with an ellipsis when it does not fit.
+    pub fn new(font: &'a Font, width: i32, height: i32) -> TextBox<'a> {
+        TextBox {
+            font,
+            width,
+            height,
+            align: Align::Left,
+            wrap: true,
+            ellipsis: true,
+            line_spacing: 0,
+        }
+    }
+
+    /// Align lines in the box
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Break lines at spaces when they get too wide. Lines are always broken at `\n`.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// End text that does not fit with an ellipsis instead of cutting it off
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Set pixels between lines
+    pub fn line_spacing(mut self, spacing: i32) -> Self {
+        self.line_spacing = spacing;
+        self
+    }
+
+    /// Width and height in pixels of text laid out in the box
+    pub fn measure(&self, text: &str) -> (i32, i32) {
+        let mut width = 0;
+        let mut lines = 0;
+        for (line, truncated) in self.lines(text) {
+            width = max(width, self.line_width(line, truncated));
+            lines += 1;
+        }
+        (width, max(lines * self.line_height() - self.line_spacing, 0))
+    }
+
+    /// Draw text laid out in the box with its top left corner at `x`, `y`.
+    /// Returns the number of lines drawn.
+    pub fn draw<C: Canvas>(&self, c: &mut C, x: i32, y: i32, text: &str, color: Color) -> i32 {
+        let mut lines = 0;
+        for (line, truncated) in self.lines(text) {
+            let lx = match self.align {
+                Align::Left => x,
+                Align::Center => x + (self.width - self.line_width(line, truncated)) / 2,
+                Align::Right => x + self.width - self.line_width(line, truncated),
+            };
+            let ly = y + lines * self.line_height();
+            let end = draw_text(c, lx, ly, self.shown(line, truncated), self.font, color);
+            if truncated && self.ellipsis {
+                draw_text(c, end, ly, ELLIPSIS, self.font, color);
+            }
+            lines += 1;
+        }
+        lines
+    }
+
+    /// Lines of text with whether each is truncated
+    fn lines<'t>(&'t self, text: &'t str) -> Lines<'t> {
+        Lines {
+            layout: self,
+            rest: text,
+            count: 0,
+        }
+    }
+
+    fn line_height(&self) -> i32 {
+        self.font.height as i32 + self.line_spacing
+    }
+
+    /// Lines that fit in the box, at least one
+    fn max_lines(&self) -> i32 {
+        max((self.height + self.line_spacing) / self.line_height(), 1)
+    }
+
+    /// Part of a line that is drawn
+    fn shown<'t>(&self, line: &'t str, truncated: bool) -> &'t str {
+        if !truncated {
+            return line;
+        }
+        let width = if self.ellipsis {
+            self.width - self.font.measure(ELLIPSIS)
+        } else {
+            self.width
+        };
+        &line[..self.fit(line, width)]
+    }
+
+    /// Width in pixels of a line as drawn
+    fn line_width(&self, line: &str, truncated: bool) -> i32 {
+        let width = self.font.measure(self.shown(line, truncated));
+        if truncated && self.ellipsis {
+            width + self.font.measure(ELLIPSIS)
+        } else {
+            width
+        }
+    }
+
+    /// Length in bytes of the longest start of `text` that fits in `width` pixels
+    fn fit(&self, text: &str, width: i32) -> usize {
+        let mut x = 0;
+        let mut prev = None;
+        for (i, ch) in text.char_indices() {
+            if let Some(p) = prev {
+                x += self.font.kern(p, ch);
+            }
+            x += self.font.advance(ch);
+            if x > width {
+                return i;
+            }
+            prev = Some(ch);
+        }
+        text.len()
+    }
+
+    /// Split the first line off `text`
+    fn break_line<'t>(&self, text: &'t str) -> (&'t str, &'t str) {
+        let (para, after) = match text.find('\n') {
+            Some(i) => (&text[..i], &text[i + 1..]),
+            None => (text, ""),
+        };
+        let len = self.fit(para, self.width);
+        if !self.wrap || len == para.len() {
+            return (para, after);
+        }
+
+        // Break at the last space that fits, or inside a word longer than the line
+        let end = para[len..].chars().next().map_or(len, |c| len + c.len_utf8());
+        let cut = match para[..end].rfind(' ') {
+            Some(i) if !para[..i].trim_end().is_empty() => i,
+            _ => max(len, para.chars().next().map_or(0, |c| c.len_utf8())),
+        };
+        // The rest of the paragraph may be only spaces, which should not become a blank line
+        let rest = text[cut..].trim_start_matches(' ');
+        (para[..cut].trim_end(), rest.strip_prefix('\n').unwrap_or(rest))
+    }
+}
+
+/// Lines of text laid out in a box
+struct Lines<'t> {
+    layout: &'t TextBox<'t>,
+    rest: &'t str,
+    count: i32,
+}
+
+impl<'t> Iterator for Lines<'t> {
+    type Item = (&'t str, bool);
+
+    fn next(&mut self) -> Option<(&'t str, bool)> {
+        let layout = self.layout;
+        if self.rest.is_empty() || self.count == layout.max_lines() {
+            return None;
+        }
+        let (line, rest) = layout.break_line(self.rest);
+        self.rest = rest;
+        self.count += 1;
+
+        let last = self.count == layout.max_lines() && !rest.is_empty();
+        let truncated = last || layout.font.measure(line) > layout.width;
+        Some((line, truncated))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fonts::FONT_6X8;
+
+    fn lines<'t>(layout: &'t TextBox<'t>, text: &'t str) -> ([&'t str; 4], usize) {
+        let mut found = [""; 4];
+        let mut count = 0;
+        for (line, _) in layout.lines(text) {
+            found[count] = line;
+            count += 1;
+        }
+        (found, count)
+    }
+
+    #[test]
+    fn wrap_keeps_following_paragraphs() {
+        let layout = TextBox::new(&FONT_6X8, 36, 64);
+        assert_eq!(lines(&layout, "hello world\nsecond"), (["hello", "world", "second", ""], 3));
+        assert_eq!(lines(&layout, "hello   \nsecond"), (["hello", "second", "", ""], 2));
+    }
+}
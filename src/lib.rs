@@ -69,29 +69,118 @@
 #![deny(warnings)]
 
 extern crate embedded_hal as hal;
+#[cfg(feature = "fontgen")]
+extern crate std;
 
 /// SSD1306 Prelude
 pub mod prelude;
 /// Commands
 pub mod cmd;
+/// Hardware scrolling
+pub mod scroll;
+/// Display controllers
+pub mod controller;
+/// Display configuration
+pub mod config;
+/// Display brightness
+pub mod brightness;
+mod power;
+/// OLED burn-in mitigation
+pub mod burnin;
+/// Software grayscale
+pub mod gray;
+/// Double buffering
+pub mod double;
+mod vscroll;
+/// Drawing targets
+pub mod canvas;
+/// 2D drawing primitives
+pub mod primitives;
+/// Arcs, polygons and curves
+pub mod shapes;
+/// Flood and pattern fills
+pub mod fill;
+/// Bitmap fonts and text
+pub mod font;
+/// Bundled fonts
+pub mod fonts;
+/// Text layout
+pub mod layout;
+/// Segment display digits
+pub mod segment;
+/// 1-bit images
+pub mod image;
+/// Dithering of gray and color images
+pub mod dither;
+/// BDF font compiler
+#[cfg(feature = "fontgen")]
+pub mod fontgen;
 
 use hal::blocking::i2c;
 use hal::digital::OutputPin;
 use hal::blocking::delay::DelayMs;
-use cmd::{AddrMode, Command, VcomhLevel};
+use brightness::{Brightness, Ramp};
+use burnin::BurnIn;
+use cmd::{AddrMode, Command, FadeInterval, FadeMode, HScrollDir, OutOfRange, Page};
+use config::DisplayConfig;
+use controller::Controller;
 use prelude::Write;
+use scroll::ScrollConfig;
+use vscroll::VScroll;
 
 /// Default i2c address
 pub const ADDRESS: u8 = 0x3C;
 const BUF_SIZE: usize = 128 * 64 / 8;
 
+/// Errors
+#[derive(Debug)]
+pub enum Error<CommE> {
+    /// Communication error
+    Comm(CommE),
+    /// Command argument out of range
+    OutOfRange,
+    /// Command not supported by the display controller
+    Unsupported,
+}
+
+impl<CommE> From<OutOfRange> for Error<CommE> {
+    fn from(_: OutOfRange) -> Error<CommE> {
+        Error::OutOfRange
+    }
+}
+
+/// Strategy used by [`Ssd1306::draw`](struct.Ssd1306.html#method.draw)
+/// to write the buffer to the display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlushMode {
+    /// Horizontal addressing mode, writing all dirty pages in one window
+    Horizontal,
+    /// Page addressing mode, writing each dirty page separately
+    Page,
+}
+
 /// Ssd1306
 pub struct Ssd1306<I2C> {
     addr: u8,
+    res: Resolution,
     width: u8,
     height: u8,
     i2c: I2C,
+    controller: Controller,
     charge_pump: bool,
+    flush_mode: FlushMode,
+    column_offset: u8,
+    display_offset: u8,
+    start_line: u8,
+    scroll: Option<ScrollConfig>,
+    vscroll: Option<VScroll>,
+    brightness: Brightness,
+    ramp: Option<Ramp>,
+    burn_in: Option<BurnIn>,
+    dirty: u8,
+    // Display RAM was changed other than by a double buffer swap, so the
+    // front buffer no longer knows what the display shows
+    unsynced: bool,
     buf: [u8; BUF_SIZE],
 }
 
@@ -101,6 +190,19 @@ where
 {
     /// Create Ssd1306 object
     pub fn new(i2c: I2C, addr: u8, res: Resolution, charge_pump: bool) -> Ssd1306<I2C> {
+        Ssd1306::with_controller(i2c, addr, res, charge_pump, Controller::Ssd1306)
+    }
+
+    /// Create Ssd1306 object for another display controller.
+    /// For the SH1106, `charge_pump` enables its DC-DC converter.
+    /// The SSD1309 and SSD1305 always run from external VCC and ignore it.
+    pub fn with_controller(
+        i2c: I2C,
+        addr: u8,
+        res: Resolution,
+        charge_pump: bool,
+        controller: Controller,
+    ) -> Ssd1306<I2C> {
         let width: u8;
         let height: u8;
 
@@ -113,9 +215,25 @@ where
         Ssd1306 {
             addr,
             i2c,
+            res,
             width,
             height,
+            controller,
             charge_pump,
+            flush_mode: match controller {
+                Controller::Sh1106 => FlushMode::Page,
+                _ => FlushMode::Horizontal,
+            },
+            column_offset: controller.column_offset(),
+            display_offset: 0,
+            start_line: 0,
+            scroll: None,
+            vscroll: None,
+            brightness: Brightness::NORMAL,
+            ramp: None,
+            burn_in: None,
+            dirty: 0xFF,
+            unsynced: true,
             buf: [0; BUF_SIZE],
         }
     }
@@ -138,38 +256,146 @@ where
         rst.set_high();
     }
 
+    /// Default display configuration for this controller and resolution
+    pub fn config(&self) -> DisplayConfig {
+        DisplayConfig::new(self.controller, self.res).charge_pump(self.charge_pump)
+    }
+
     /// Initialize display
-    pub fn init(&mut self) -> Result<(), I2C::Error> {
+    pub fn init(&mut self) -> Result<(), Error<I2C::Error>> {
+        let config = self.config();
+        self.init_with(config)
+    }
+
+    /// Initialize display with custom configuration
+    pub fn init_with(&mut self, config: DisplayConfig) -> Result<(), Error<I2C::Error>> {
+        if config.column_offset as u16 + self.width as u16 > self.controller.columns() as u16 {
+            return Err(Error::OutOfRange);
+        }
+        let addr_mode = self.controller.supports(&Command::AddressMode(AddrMode::Page));
+        if !addr_mode && config.flush_mode != FlushMode::Page {
+            return Err(Error::Unsupported);
+        }
+
+        // Check every value before the display is touched
+        let (fosc, div) = config.clock_div;
+        let clock_div = Command::display_clock_div(fosc, div)?;
+        let multiplex = Command::multiplex(config.multiplex)?;
+        let display_offset = Command::display_offset(config.display_offset)?;
+        let start_line = Command::start_line(config.start_line)?;
+        let (phase1, phase2) = config.pre_charge;
+        let pre_charge = Command::pre_charge_period(phase1, phase2)?;
+
         self.send_command(Command::DisplayOn(false))?;
-        self.send_command(Command::DisplayClockDiv(0x8, 0x0))?;
-        let mpx = self.height - 1;
-        self.send_command(Command::Multiplex(mpx))?;
-        self.send_command(Command::DisplayOffset(0))?;
-        self.send_command(Command::StartLine(0))?;
-        let charge_pump = self.charge_pump;
-        self.send_command(Command::ChargePump(charge_pump))?;
-        self.send_command(Command::AddressMode(AddrMode::Horizontal))?;
-        self.send_command(Command::SegmentRemap(true))?;
-        self.send_command(Command::ReverseComDir(true))?;
-        if self.width == 128 && self.height == 32 {
-            self.send_command(Command::ComPinConfig(false, false))?;
-        } else if self.width == 128 && self.height == 64 {
-            self.send_command(Command::ComPinConfig(true, false))?;
-        } else if self.width == 96 && self.height == 16 {
-            self.send_command(Command::ComPinConfig(false, false))?;
-        }
-        self.send_command(Command::Contrast(0x8F))?;
-        self.send_command(Command::PreChargePeriod(0x1, 0xF))?;
-        self.send_command(Command::VcomhDeselect(VcomhLevel::Auto))?;
+        self.send_command(clock_div)?;
+        self.send_command(multiplex)?;
+        self.send_command(display_offset)?;
+        self.send_command(start_line)?;
+        match self.controller {
+            Controller::Ssd1306 => self.send_command(Command::ChargePump(config.charge_pump))?,
+            Controller::Sh1106 => self.send_command(Command::DcDc(config.charge_pump))?,
+            Controller::Ssd1309 => (),
+            Controller::Ssd1305 => self.send_command(Command::ExternalVcc)?,
+        }
+        if addr_mode {
+            self.send_command(Command::AddressMode(config.flush_mode.into()))?;
+        }
+        self.send_command(Command::SegmentRemap(config.segment_remap))?;
+        self.send_command(Command::ReverseComDir(config.reverse_com_dir))?;
+        let (alt, lr) = config.com_pins;
+        self.send_command(Command::ComPinConfig(alt, lr))?;
+        self.send_command(Command::Contrast(config.contrast))?;
+        self.send_command(pre_charge)?;
+        self.send_command(Command::VcomhDeselect(config.vcomh))?;
         self.send_command(Command::AllOn(false))?;
-        self.send_command(Command::Invert(false))?;
-        self.send_command(Command::EnableScroll(false))?;
+        self.send_command(Command::Invert(config.invert))?;
+        if self.controller.supports(&Command::EnableScroll(false)) {
+            self.send_command(Command::EnableScroll(false))?;
+        }
+        self.scroll = None;
         self.send_command(Command::DisplayOn(true))?;
+
+        self.flush_mode = config.flush_mode;
+        self.column_offset = config.column_offset;
+        self.display_offset = config.display_offset;
+        self.start_line = config.start_line;
+        self.vscroll = None;
+        self.burn_in = None;
+        self.charge_pump = config.charge_pump;
+        self.brightness = Brightness::custom(phase1, phase2, config.contrast);
+        self.ramp = None;
+        self.dirty = 0xFF;
         Ok(())
     }
 
-    fn send_command(&mut self, cmd: Command) -> Result<(), I2C::Error> {
-        cmd.send(&mut self.i2c, self.addr)
+    /// Set the addressing mode used to write the buffer to the display.
+    /// The SH1106 only supports `FlushMode::Page`.
+    pub fn set_flush_mode(&mut self, mode: FlushMode) -> Result<(), Error<I2C::Error>> {
+        // Controllers without the command always use page addressing
+        let cmd = Command::AddressMode(mode.into());
+        if self.controller.supports(&cmd) {
+            self.send_command(cmd)?;
+        } else if mode != FlushMode::Page {
+            return Err(Error::Unsupported);
+        }
+        self.flush_mode = mode;
+        Ok(())
+    }
+
+    /// Start hardware scrolling. Scrolling is stopped and restarted
+    /// around every [`draw`](#method.draw).
+    pub fn start_scroll(&mut self, config: ScrollConfig) -> Result<(), Error<I2C::Error>> {
+        if config.end_page() as u8 >= self.height / 8 {
+            return Err(Error::OutOfRange);
+        }
+        let setup = config.setup()?;
+
+        self.send_command(Command::EnableScroll(false))?;
+        if let ScrollConfig::Diagonal(..) = config {
+            let rows = self.height;
+            self.send_command(Command::vscroll_area(0, rows)?)?;
+        }
+        self.send_command(setup)?;
+        self.send_command(Command::EnableScroll(true))?;
+        self.scroll = Some(config);
+        Ok(())
+    }
+
+    /// Stop hardware scrolling. The display contents must be redrawn afterwards.
+    pub fn stop_scroll(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::EnableScroll(false))?;
+        self.scroll = None;
+        self.dirty = 0xFF;
+        self.unsynced = true;
+        Ok(())
+    }
+
+    /// Fade display out, stepping brightness down every `interval` frames
+    pub fn fade_out(&mut self, interval: FadeInterval) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::FadeOutBlink(FadeMode::FadeOut, interval))
+    }
+
+    /// Blink display, stepping brightness down and up every `interval` frames
+    pub fn blink(&mut self, interval: FadeInterval) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::FadeOutBlink(FadeMode::Blink, interval))
+    }
+
+    /// Stop fading out or blinking and restore full brightness
+    pub fn stop_fade(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::FadeOutBlink(FadeMode::Disabled, FadeInterval::F8))
+    }
+
+    /// Enable or disable zoom in, showing the top half of the display at double height.
+    /// Only works on panels using the alternative COM pin configuration.
+    pub fn set_zoom(&mut self, zoom: bool) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::ZoomIn(zoom))
+    }
+
+    fn send_command(&mut self, cmd: Command) -> Result<(), Error<I2C::Error>> {
+        if !self.controller.supports(&cmd) {
+            return Err(Error::Unsupported);
+        }
+        cmd.send(&mut self.i2c, self.addr).map_err(Error::Comm)
     }
 
     /// Clear output buffer
@@ -177,22 +403,28 @@ where
         for i in 0..self.buf.len() {
             self.buf[i] = 0;
         }
+        self.dirty = 0xFF;
     }
 
     /// Turn pixel off
     pub fn pixel_on(&mut self, x: u8, y: u8) {
+        let y = self.ram_row(y);
         let b = &mut self.buf[((y as usize) / 8 * 128) + (x as usize)];
         *b |= 1 << (y % 8);
+        self.dirty |= 1 << (y / 8);
     }
 
     /// Turn pixel on
     pub fn pixel_off(&mut self, x: u8, y: u8) {
+        let y = self.ram_row(y);
         let b = &mut self.buf[((y as usize) / 8 * 128) + (x as usize)];
         *b &= !(1 << (y % 8));
+        self.dirty |= 1 << (y / 8);
     }
 
     /// Swap pixel value
     pub fn invert_pixel(&mut self, x: u8, y: u8) {
+        let y = self.ram_row(y);
         let byte = &mut self.buf[((y as usize) / 8 * 128) + (x as usize)];
         let bit = 1 << (y % 8);
         if *byte & bit == 0 {
@@ -200,17 +432,171 @@ where
         } else {
             *byte &= !bit;
         }
+        self.dirty |= 1 << (y / 8);
+    }
+
+    /// Scroll the contents of a window by one column, on the display and in the buffer.
+    /// Columns shifted out of one side of the window come back in on the other.
+    /// The display needs at least two frames to complete the scroll before the
+    /// next command is sent.
+    pub fn scroll_content(
+        &mut self,
+        dir: HScrollDir,
+        start: Page,
+        end: Page,
+        start_col: u8,
+        end_col: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        let cmd = Command::content_scroll(dir, start, end, start_col, end_col)?;
+        self.send_command(cmd)?;
+        self.unsynced = true;
+
+        for page in (start as usize)..(end as usize + 1) {
+            let row = &mut self.buf[page * 128..(page + 1) * 128];
+            let window = &mut row[start_col as usize..end_col as usize + 1];
+            match dir {
+                HScrollDir::LeftToRight => window.rotate_right(1),
+                HScrollDir::RightToLeft => window.rotate_left(1),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw pages of the buffer changed since the last draw to display
+    pub fn draw(&mut self) -> Result<(), Error<I2C::Error>> {
+        if self.dirty != 0 {
+            self.burn_in_activity()?;
+        }
+        self.flush()
+    }
+
+    fn flush(&mut self) -> Result<(), Error<I2C::Error>> {
+        // Leave a running scroll alone when there is nothing to write
+        let dirty = self.dirty & self.page_mask();
+        if dirty == 0 {
+            return self.apply_scroll_offset();
+        }
+
+        self.pause_scroll(|disp| {
+            let dirty = disp.dirty & disp.page_mask();
+            disp.write_pages(dirty, |disp, page| disp.write_page(page))?;
+            disp.dirty = 0;
+            disp.apply_scroll_offset()
+        })
+    }
+
+    /// Run `write`, which writes display RAM, with hardware scrolling stopped
+    pub(crate) fn pause_scroll<F>(&mut self, write: F) -> Result<(), Error<I2C::Error>>
+    where
+        F: FnOnce(&mut Self) -> Result<(), Error<I2C::Error>>,
+    {
+        // RAM must not be written while scrolling is active
+        let scroll = self.scroll;
+        if scroll.is_some() {
+            self.stop_scroll()?;
+        }
+        write(self)?;
+        self.unsynced = true;
+        if let Some(config) = scroll {
+            self.start_scroll(config)?;
+        }
+        Ok(())
+    }
+
+    /// Mask of the pages written to the display
+    fn page_mask(&self) -> u8 {
+        if self.vscroll.is_some() {
+            return 0xFF;
+        }
+        // Rows the burn-in shift brings into view must be written as well
+        let rows = self.height as u16 + self.burn_in.map_or(0, |state| state.rows_below() as u16);
+        0xFF >> (8 - ((rows + 7) >> 3).min(8))
     }
 
-    /// Draw buffer to display
-    pub fn draw(&mut self) -> Result<(), I2C::Error> {
+    /// Write the pages set in `pages` with `write`, which sends the data
+    /// for all columns of one page
+    fn write_pages<F>(&mut self, pages: u8, mut write: F) -> Result<(), Error<I2C::Error>>
+    where
+        F: FnMut(&mut Self, u8) -> Result<(), Error<I2C::Error>>,
+    {
+        if pages == 0 {
+            return Ok(());
+        }
         let ecol = self.width - 1;
-        let epage = self.height - 1;
-        self.send_command(Command::ColumnAddress(0, ecol))?;
-        self.send_command(Command::PageAddress(0.into(), epage.into()))?;
-        self.i2c.write_data(self.addr, &self.buf)?;
+
+        match self.flush_mode {
+            FlushMode::Horizontal => {
+                let first = pages.trailing_zeros() as u8;
+                let last = 7 - pages.leading_zeros() as u8;
+                self.set_window(first, last, 0, ecol)?;
+                for page in first..last + 1 {
+                    write(self, page)?;
+                }
+            }
+            FlushMode::Page => for page in 0..8 {
+                if pages & (1 << page) != 0 {
+                    self.set_window(page, page, 0, ecol)?;
+                    write(self, page)?;
+                }
+            },
+        }
         Ok(())
     }
+
+    /// Point display RAM writes at column `col` of `page`. In horizontal mode
+    /// writes wrap from `end_col` onto the next page, up to `last_page`.
+    fn set_window(
+        &mut self,
+        page: u8,
+        last_page: u8,
+        col: u8,
+        end_col: u8,
+    ) -> Result<(), Error<I2C::Error>> {
+        // The offset is checked to leave room for the full width in init_with
+        let scol = self.column_offset + col;
+        match self.flush_mode {
+            FlushMode::Horizontal => {
+                let ecol = self.column_offset + end_col;
+                self.send_command(Command::column_address_for(self.controller, scol, ecol)?)?;
+                self.send_command(Command::PageAddress((page * 8).into(), (last_page * 8).into()))
+            }
+            FlushMode::Page => {
+                self.send_command(Command::PageStart((page * 8).into()))?;
+                self.send_command(Command::lower_col_start(scol & 0xF)?)?;
+                self.send_command(Command::upper_col_start(scol >> 4)?)
+            }
+        }
+    }
+
+    fn write_page(&mut self, page: u8) -> Result<(), Error<I2C::Error>> {
+        let width = self.width as usize;
+        let start = page as usize * 128;
+        let mut row = [0; 128];
+        row[..width].copy_from_slice(&self.buf[start..start + width]);
+        self.write_row(page, &row[..width])
+    }
+
+    /// Write `row`, holding every column of `page`, to display RAM altered for burn-in
+    pub(crate) fn write_row(&mut self, page: u8, row: &[u8]) -> Result<(), Error<I2C::Error>> {
+        match self.burn_in {
+            Some(ref state) if state.alters(page) => {
+                let mut altered = [0; 128];
+                state.alter(page, row, &mut altered[..row.len()]);
+                self.i2c.write_data(self.addr, &altered[..row.len()])
+            }
+            _ => self.i2c.write_data(self.addr, row),
+        }.map_err(Error::Comm)
+    }
+}
+
+impl From<FlushMode> for AddrMode {
+    fn from(mode: FlushMode) -> AddrMode {
+        match mode {
+            FlushMode::Horizontal => AddrMode::Horizontal,
+            FlushMode::Page => AddrMode::Page,
+        }
+    }
 }
 
 /// Display resolution
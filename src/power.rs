@@ -0,0 +1,72 @@
+use hal::blocking::delay::DelayMs;
+use hal::blocking::i2c;
+use hal::digital::OutputPin;
+
+use cmd::Command;
+use controller::Controller;
+use {Error, Ssd1306};
+
+/// Time for VCC to settle after power up, and to discharge after power down
+const VCC_DELAY_MS: u8 = 100;
+
+impl<I2C> Ssd1306<I2C>
+where
+    I2C: i2c::Write,
+{
+    /// Turn display off, then disable the charge pump or DC-DC converter if used.
+    /// Display RAM is kept while sleeping.
+    pub fn sleep(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.send_command(Command::DisplayOn(false))?;
+        self.set_pump(false)
+    }
+
+    /// Enable the charge pump or DC-DC converter if used, then turn display on
+    pub fn wake(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.set_pump(true)?;
+        self.send_command(Command::DisplayOn(true))
+    }
+
+    /// Put display to sleep, then switch VCC off through its enable pin
+    /// and wait for it to discharge
+    pub fn power_down<VCC, DELAY>(
+        &mut self,
+        vcc: &mut VCC,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<I2C::Error>>
+    where
+        VCC: OutputPin,
+        DELAY: DelayMs<u8>,
+    {
+        self.sleep()?;
+        vcc.set_low();
+        delay.delay_ms(VCC_DELAY_MS);
+        Ok(())
+    }
+
+    /// Switch VCC on through its enable pin, wait for it to settle,
+    /// then wake display
+    pub fn power_up<VCC, DELAY>(
+        &mut self,
+        vcc: &mut VCC,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<I2C::Error>>
+    where
+        VCC: OutputPin,
+        DELAY: DelayMs<u8>,
+    {
+        vcc.set_high();
+        delay.delay_ms(VCC_DELAY_MS);
+        self.wake()
+    }
+
+    fn set_pump(&mut self, on: bool) -> Result<(), Error<I2C::Error>> {
+        if !self.charge_pump {
+            return Ok(());
+        }
+        match self.controller {
+            Controller::Ssd1306 => self.send_command(Command::ChargePump(on)),
+            Controller::Sh1106 => self.send_command(Command::DcDc(on)),
+            Controller::Ssd1309 | Controller::Ssd1305 => Ok(()),
+        }
+    }
+}
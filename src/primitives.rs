@@ -0,0 +1,285 @@
+use core::cmp::{max, min};
+use core::mem;
+
+use canvas::{Canvas, Color};
+
+/// Draw a line between two points, both included
+pub fn line<C: Canvas>(c: &mut C, x0: i32, y0: i32, x1: i32, y1: i32, color: Color) {
+    if y0 == y1 {
+        hline(c, x0, x1, y0, color);
+    } else if x0 == x1 {
+        vline(c, x0, y0, y1, color);
+    } else {
+        bresenham(c, x0, y0, x1, y1, color, true);
+    }
+}
+
+/// Draw a horizontal line from column `x0` to `x1` of row `y`
+pub fn hline<C: Canvas>(c: &mut C, x0: i32, x1: i32, y: i32, color: Color) {
+    let (width, height) = c.size();
+    if y < 0 || y >= height as i32 {
+        return;
+    }
+    let lo = max(min(x0, x1), 0);
+    let hi = min(max(x0, x1), width as i32 - 1);
+    let mask = 1 << (y & 7);
+    for x in lo..hi + 1 {
+        c.apply_mask(x as u16, (y >> 3) as u16, mask, color);
+    }
+}
+
+/// Draw a vertical line from row `y0` to `y1` of column `x`, a page byte at a time
+pub fn vline<C: Canvas>(c: &mut C, x: i32, y0: i32, y1: i32, color: Color) {
+    let (width, height) = c.size();
+    if x < 0 || x >= width as i32 {
+        return;
+    }
+    let mut y = max(min(y0, y1), 0);
+    let y1 = min(max(y0, y1), height as i32 - 1);
+    while y <= y1 {
+        let page = y >> 3;
+        let last = min(y1, page * 8 + 7);
+        let mask = (0xFF << (y & 7)) & (0xFF >> (7 - (last & 7)));
+        c.apply_mask(x as u16, page as u16, mask, color);
+        y = last + 1;
+    }
+}
+
+/// Draw the outline of a rectangle
+pub fn rect<C: Canvas>(c: &mut C, x: i32, y: i32, w: i32, h: i32, color: Color) {
+    if w <= 0 || h <= 0 {
+        return;
+    }
+    hline(c, x, x + w - 1, y, color);
+    if h > 1 {
+        hline(c, x, x + w - 1, y + h - 1, color);
+    }
+    if h > 2 {
+        vline(c, x, y + 1, y + h - 2, color);
+        if w > 1 {
+            vline(c, x + w - 1, y + 1, y + h - 2, color);
+        }
+    }
+}
+
+/// Draw a filled rectangle
+pub fn fill_rect<C: Canvas>(c: &mut C, x: i32, y: i32, w: i32, h: i32, color: Color) {
+    if w <= 0 || h <= 0 {
+        return;
+    }
+    for col in x..x + w {
+        vline(c, col, y, y + h - 1, color);
+    }
+}
+
+/// Draw the outline of a circle
+pub fn circle<C: Canvas>(c: &mut C, cx: i32, cy: i32, r: i32, color: Color) {
+    ellipse(c, cx, cy, r, r, color);
+}
+
+/// Draw a filled circle
+pub fn fill_circle<C: Canvas>(c: &mut C, cx: i32, cy: i32, r: i32, color: Color) {
+    fill_ellipse(c, cx, cy, r, r, color);
+}
+
+/// Draw the outline of an ellipse with radii `rx` and `ry`
+pub fn ellipse<C: Canvas>(c: &mut C, cx: i32, cy: i32, rx: i32, ry: i32, color: Color) {
+    if rx < 0 || ry < 0 {
+        return;
+    }
+    quadrant(rx, ry, |a, b| {
+        c.set_pixel(cx + a, cy + b, color);
+        if a != 0 {
+            c.set_pixel(cx - a, cy + b, color);
+        }
+        if b != 0 {
+            c.set_pixel(cx + a, cy - b, color);
+            if a != 0 {
+                c.set_pixel(cx - a, cy - b, color);
+            }
+        }
+    });
+}
+
+/// Draw a filled ellipse with radii `rx` and `ry`
+pub fn fill_ellipse<C: Canvas>(c: &mut C, cx: i32, cy: i32, rx: i32, ry: i32, color: Color) {
+    ellipse_spans(cx, cy, rx, ry, |x0, x1, y| hline(c, x0, x1, y, color));
+}
+
+/// Draw the outline of a triangle
+pub fn triangle<C: Canvas>(c: &mut C, p0: (i32, i32), p1: (i32, i32), p2: (i32, i32), color: Color) {
+    // Each edge leaves out its end so corners are drawn once
+    bresenham(c, p0.0, p0.1, p1.0, p1.1, color, false);
+    bresenham(c, p1.0, p1.1, p2.0, p2.1, color, false);
+    bresenham(c, p2.0, p2.1, p0.0, p0.1, color, false);
+}
+
+/// Draw a filled triangle
+pub fn fill_triangle<C: Canvas>(c: &mut C, p0: (i32, i32), p1: (i32, i32), p2: (i32, i32), color: Color) {
+    triangle_spans(p0, p1, p2, |x0, x1, y| hline(c, x0, x1, y, color));
+}
+
+/// Draw the outline of a rectangle with corners of radius `r`
+pub fn rounded_rect<C: Canvas>(c: &mut C, x: i32, y: i32, w: i32, h: i32, r: i32, color: Color) {
+    let r = min(r, (min(w, h) - 1) / 2);
+    if r <= 0 {
+        return rect(c, x, y, w, h, color);
+    }
+    let (right, bottom) = (x + w - 1, y + h - 1);
+    hline(c, x + r, right - r, y, color);
+    hline(c, x + r, right - r, bottom, color);
+    vline(c, x, y + r, bottom - r, color);
+    vline(c, right, y + r, bottom - r, color);
+
+    // The ends of the arcs are part of the straight edges
+    quadrant(r, r, |a, b| {
+        if a != 0 && b != 0 {
+            c.set_pixel(x + r - a, y + r - b, color);
+            c.set_pixel(right - r + a, y + r - b, color);
+            c.set_pixel(x + r - a, bottom - r + b, color);
+            c.set_pixel(right - r + a, bottom - r + b, color);
+        }
+    });
+}
+
+/// Draw a filled rectangle with corners of radius `r`
+pub fn fill_rounded_rect<C: Canvas>(c: &mut C, x: i32, y: i32, w: i32, h: i32, r: i32, color: Color) {
+    rounded_rect_spans(x, y, w, h, r, |x0, x1, y| hline(c, x0, x1, y, color));
+}
+
+/// Integer square root, rounded to nearest
+pub(crate) fn sqrt(n: i64) -> i64 {
+    fn floor(n: i64) -> i64 {
+        if n <= 0 {
+            return 0;
+        }
+        let mut x = n;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + n / x) / 2;
+        }
+        x
+    }
+    floor(n + floor(n))
+}
+
+/// Line with Bresenham's algorithm, optionally leaving out the end point
+pub(crate) fn bresenham<C: Canvas>(c: &mut C, x0: i32, y0: i32, x1: i32, y1: i32, color: Color, last: bool) {
+    let (dx, sx) = ((x1 - x0).abs(), if x0 < x1 { 1 } else { -1 });
+    let (dy, sy) = (-(y1 - y0).abs(), if y0 < y1 { 1 } else { -1 });
+    let mut err = dx + dy;
+    let (mut x, mut y) = (x0, y0);
+    loop {
+        if x == x1 && y == y1 {
+            if last {
+                c.set_pixel(x, y, color);
+            }
+            return;
+        }
+        c.set_pixel(x, y, color);
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+/// Call `point` once for each point of the first quadrant of an ellipse
+/// outline, stepping columns where it is flat and rows where it is steep
+pub(crate) fn quadrant<F: FnMut(i32, i32)>(rx: i32, ry: i32, mut point: F) {
+    let (rx2, ry2) = (rx as i64 * rx as i64, ry as i64 * ry as i64);
+    if rx == 0 || ry == 0 {
+        for a in 0..rx + 1 {
+            point(a, 0);
+        }
+        for b in 1..ry + 1 {
+            point(0, b);
+        }
+        return;
+    }
+
+    let mut row = ry + 1;
+    for a in 0..rx + 1 {
+        let b = sqrt(ry2 * (rx2 - a as i64 * a as i64) / rx2);
+        if ry2 * a as i64 > rx2 * b {
+            break;
+        }
+        point(a, b as i32);
+        row = b as i32;
+    }
+    for b in (0..row).rev() {
+        let a = sqrt(rx2 * (ry2 - b as i64 * b as i64) / ry2);
+        point(a as i32, b);
+    }
+}
+
+/// Call `span` with the first and last column of each row of a filled ellipse
+pub(crate) fn ellipse_spans<F: FnMut(i32, i32, i32)>(cx: i32, cy: i32, rx: i32, ry: i32, mut span: F) {
+    if rx < 0 || ry < 0 {
+        return;
+    }
+    if ry == 0 {
+        return span(cx - rx, cx + rx, cy);
+    }
+    let (rx2, ry2) = (rx as i64 * rx as i64, ry as i64 * ry as i64);
+    for b in -ry..ry + 1 {
+        let a = sqrt(rx2 * (ry2 - b as i64 * b as i64) / ry2) as i32;
+        span(cx - a, cx + a, cy + b);
+    }
+}
+
+/// Call `span` with the first and last column of each row of a filled triangle
+pub(crate) fn triangle_spans<F>(p0: (i32, i32), p1: (i32, i32), p2: (i32, i32), mut span: F)
+where
+    F: FnMut(i32, i32, i32),
+{
+    let (mut p0, mut p1, mut p2) = (p0, p1, p2);
+    if p0.1 > p1.1 {
+        mem::swap(&mut p0, &mut p1);
+    }
+    if p1.1 > p2.1 {
+        mem::swap(&mut p1, &mut p2);
+    }
+    if p0.1 > p1.1 {
+        mem::swap(&mut p0, &mut p1);
+    }
+    let ((x0, y0), (x1, y1), (x2, y2)) = (p0, p1, p2);
+    if y0 == y2 {
+        return span(min(x0, min(x1, x2)), max(x0, max(x1, x2)), y0);
+    }
+
+    for y in y0..y2 + 1 {
+        let xa = x0 + (x2 - x0) * (y - y0) / (y2 - y0);
+        let xb = if y < y1 {
+            x0 + (x1 - x0) * (y - y0) / (y1 - y0)
+        } else if y2 > y1 {
+            x1 + (x2 - x1) * (y - y1) / (y2 - y1)
+        } else {
+            x1
+        };
+        span(min(xa, xb), max(xa, xb), y);
+    }
+}
+
+/// Call `span` with the first and last column of each row of a filled rounded rectangle
+pub(crate) fn rounded_rect_spans<F>(x: i32, y: i32, w: i32, h: i32, r: i32, mut span: F)
+where
+    F: FnMut(i32, i32, i32),
+{
+    if w <= 0 || h <= 0 {
+        return;
+    }
+    let r = max(min(r, (min(w, h) - 1) / 2), 0);
+    let (right, bottom) = (x + w - 1, y + h - 1);
+    for row in y..bottom + 1 {
+        let b = max(max(y + r - row, row - (bottom - r)), 0);
+        let a = sqrt(r as i64 * r as i64 - b as i64 * b as i64) as i32;
+        span(x + r - a, right - r + a, row);
+    }
+}
@@ -0,0 +1,36 @@
+use cmd::{Command, HScrollDir, NFrames, OutOfRange, Page, VHScrollDir};
+
+/// Hardware scroll configuration
+#[derive(Debug, Clone, Copy)]
+pub enum ScrollConfig {
+    /// Horizontal scroll.
+    /// Values are scroll direction, start page, end page
+    /// and number of frames per step.
+    Horizontal(HScrollDir, Page, Page, NFrames),
+    /// Vertical and horizontal scroll.
+    /// Values are scroll direction, start page, end page,
+    /// number of frames per step and rows per step (0-63).
+    Diagonal(VHScrollDir, Page, Page, NFrames, u8),
+}
+
+impl ScrollConfig {
+    /// Last page scrolled horizontally
+    pub fn end_page(&self) -> Page {
+        match *self {
+            ScrollConfig::Horizontal(_, _, end, _) => end,
+            ScrollConfig::Diagonal(_, _, end, _, _) => end,
+        }
+    }
+
+    /// Command setting up this scroll
+    pub fn setup(&self) -> Result<Command, OutOfRange> {
+        match *self {
+            ScrollConfig::Horizontal(dir, start, end, rate) => {
+                Command::hscroll_setup(dir, start, end, rate)
+            }
+            ScrollConfig::Diagonal(dir, start, end, rate, offset) => {
+                Command::vhscroll_setup(dir, start, end, rate, offset)
+            }
+        }
+    }
+}
@@ -0,0 +1,370 @@
+use core::cmp::max;
+
+use canvas::{Canvas, Color};
+use cmd::OutOfRange;
+use primitives::{fill_rect, line};
+use shapes::fill_polygon;
+
+const A: u16 = 1 << 0;
+const B: u16 = 1 << 1;
+const C: u16 = 1 << 2;
+const D: u16 = 1 << 3;
+const E: u16 = 1 << 4;
+const F: u16 = 1 << 5;
+const G1: u16 = 1 << 6;
+const G2: u16 = 1 << 7;
+/// Upper left diagonal
+const H: u16 = 1 << 8;
+/// Upper middle
+const I: u16 = 1 << 9;
+/// Upper right diagonal
+const J: u16 = 1 << 10;
+/// Lower left diagonal
+const K: u16 = 1 << 11;
+/// Lower middle
+const L: u16 = 1 << 12;
+/// Lower right diagonal
+const M: u16 = 1 << 13;
+const G: u16 = G1 | G2;
+
+/// Digits and letters a seven-segment display can show
+const SEVEN: [(char, u16); 32] = [
+    ('0', A | B | C | D | E | F),
+    ('1', B | C),
+    ('2', A | B | D | E | G),
+    ('3', A | B | C | D | G),
+    ('4', B | C | F | G),
+    ('5', A | C | D | F | G),
+    ('6', A | C | D | E | F | G),
+    ('7', A | B | C),
+    ('8', A | B | C | D | E | F | G),
+    ('9', A | B | C | D | F | G),
+    ('A', A | B | C | E | F | G),
+    ('B', C | D | E | F | G),
+    ('C', A | D | E | F),
+    ('D', B | C | D | E | G),
+    ('E', A | D | E | F | G),
+    ('F', A | E | F | G),
+    ('G', A | C | D | E | F),
+    ('H', B | C | E | F | G),
+    ('I', E | F),
+    ('J', B | C | D | E),
+    ('L', D | E | F),
+    ('N', C | E | G),
+    ('O', C | D | E | G),
+    ('P', A | B | E | F | G),
+    ('Q', A | B | C | F | G),
+    ('R', E | G),
+    ('S', A | C | D | F | G),
+    ('T', D | E | F | G),
+    ('U', B | C | D | E | F),
+    ('Y', B | C | D | F | G),
+    ('-', G),
+    ('_', D),
+];
+
+/// Characters a fourteen-segment display can show besides the seven-segment digits
+const FOURTEEN: [(char, u16); 34] = [
+    ('%', C | F | J | K),
+    ('*', G | H | I | J | K | L | M),
+    ('+', G | I | L),
+    ('-', G),
+    ('/', J | K),
+    ('<', J | M),
+    ('=', D | G),
+    ('>', H | K),
+    ('A', A | B | C | E | F | G),
+    ('B', A | B | C | D | G2 | I | L),
+    ('C', A | D | E | F),
+    ('D', A | B | C | D | I | L),
+    ('E', A | D | E | F | G),
+    ('F', A | E | F | G1),
+    ('G', A | C | D | E | F | G2),
+    ('H', B | C | E | F | G),
+    ('I', A | D | I | L),
+    ('J', B | C | D | E),
+    ('K', E | F | G1 | J | M),
+    ('L', D | E | F),
+    ('M', B | C | E | F | H | J),
+    ('N', B | C | E | F | H | M),
+    ('O', A | B | C | D | E | F),
+    ('P', A | B | E | F | G),
+    ('Q', A | B | C | D | E | F | M),
+    ('R', A | B | E | F | G | M),
+    ('S', A | C | D | F | G),
+    ('T', A | I | L),
+    ('U', B | C | D | E | F),
+    ('V', E | F | J | K),
+    ('W', B | C | E | F | K | M),
+    ('X', H | J | K | M),
+    ('Y', H | J | L),
+    ('Z', A | D | J | K),
+];
+
+/// Size and kind of segment display characters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SegmentStyle {
+    height: i32,
+    width: i32,
+    thickness: i32,
+    spacing: i32,
+    fourteen: bool,
+}
+
+impl SegmentStyle {
+    /// Seven-segment characters `height` pixels tall and half as wide
+    pub fn new(height: i32) -> SegmentStyle {
+        SegmentStyle {
+            height,
+            width: max(height / 2, 3),
+            thickness: max(height / 8, 1),
+            spacing: max(height / 8, 1),
+            fourteen: false,
+        }
+    }
+
+    /// Set character width in pixels
+    pub fn width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set segment thickness in pixels
+    pub fn thickness(mut self, thickness: i32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set pixels between characters
+    pub fn spacing(mut self, spacing: i32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Use fourteen segments, which can show all letters
+    pub fn fourteen(mut self, fourteen: bool) -> Self {
+        self.fourteen = fourteen;
+        self
+    }
+
+    /// Width of text in pixels
+    pub fn measure(&self, text: &str) -> i32 {
+        text.chars().map(|c| self.advance(c)).sum()
+    }
+
+    /// Columns from the start of a character to the next
+    fn advance(&self, c: char) -> i32 {
+        let width = match c {
+            '.' | ':' => self.dot(),
+            '°' => self.width / 2,
+            _ => self.width,
+        };
+        width + self.spacing
+    }
+
+    /// Size of decimal points
+    fn dot(&self) -> i32 {
+        self.thickness / 2 * 2 + 1
+    }
+
+    /// Segments lit for a character
+    fn segments(&self, c: char) -> u16 {
+        let c = c.to_ascii_uppercase();
+        let find = |table: &[(char, u16)]| table.iter().find(|e| e.0 == c).map(|e| e.1);
+        if self.fourteen && !c.is_ascii_digit() {
+            find(&FOURTEEN).unwrap_or(0)
+        } else {
+            find(&SEVEN).unwrap_or(0)
+        }
+    }
+}
+
+/// Draw text as segment display characters with their top left corner at
+/// `x`, `y`. Digits, decimal points, colons, minus signs, `°` and the letters
+/// the display can show are drawn, other characters are left blank.
+/// Returns the column after the text.
+pub fn draw_segments<C: Canvas>(c: &mut C, x: i32, y: i32, text: &str, style: &SegmentStyle, color: Color) -> i32 {
+    let (h, t) = (style.height, style.thickness);
+    let dot = style.dot();
+    let mut x = x;
+    for ch in text.chars() {
+        match ch {
+            '.' => fill_rect(c, x, y + h - dot, dot, dot, color),
+            ':' => {
+                fill_rect(c, x, y + h / 3 - dot / 2, dot, dot, color);
+                fill_rect(c, x, y + h * 2 / 3 - dot / 2, dot, dot, color);
+            }
+            '°' => draw_char(c, x, y, (style.width / 2, h / 2), t, A | B | F | G, color),
+            _ => draw_char(c, x, y, (style.width, h), t, style.segments(ch), color),
+        }
+        x += style.advance(ch);
+    }
+    x
+}
+
+/// Draw the lit segments of a character of `size` pixels
+fn draw_char<C: Canvas>(c: &mut C, x: i32, y: i32, size: (i32, i32), t: i32, lit: u16, color: Color) {
+    let (w, h) = size;
+    let hw = t / 2;
+    let gap = max(t / 4, 1);
+    let (lx, cx, rx) = (x + hw, x + (w - 1) / 2, x + w - 1 - hw);
+    let (ty, my, by) = (y + hw, y + (h - 1) / 2, y + h - 1 - hw);
+    let d = hw + gap;
+
+    let bars = [
+        (A, (lx + gap, ty), (rx - gap, ty)),
+        (B, (rx, ty + gap), (rx, my - gap)),
+        (C, (rx, my + gap), (rx, by - gap)),
+        (D, (lx + gap, by), (rx - gap, by)),
+        (E, (lx, my + gap), (lx, by - gap)),
+        (F, (lx, ty + gap), (lx, my - gap)),
+        (I, (cx, ty + gap), (cx, my - gap)),
+        (L, (cx, my + gap), (cx, by - gap)),
+    ];
+    for &(seg, p0, p1) in bars.iter() {
+        if lit & seg != 0 {
+            bar(c, p0, p1, hw, color);
+        }
+    }
+
+    // The middle bar is split in two when the center segments are used
+    match lit & G {
+        G if lit & (I | L) == 0 => bar(c, (lx + gap, my), (rx - gap, my), hw, color),
+        _ => {
+            if lit & G1 != 0 {
+                bar(c, (lx + gap, my), (cx - gap, my), hw, color);
+            }
+            if lit & G2 != 0 {
+                bar(c, (cx + gap, my), (rx - gap, my), hw, color);
+            }
+        }
+    }
+
+    let diagonals = [
+        (H, (lx + d, ty + d), (cx - d, my - d)),
+        (J, (rx - d, ty + d), (cx + d, my - d)),
+        (K, (lx + d, by - d), (cx - d, my + d)),
+        (M, (rx - d, by - d), (cx + d, my + d)),
+    ];
+    for &(seg, p0, p1) in diagonals.iter() {
+        if lit & seg != 0 {
+            diagonal(c, p0, p1, t, color);
+        }
+    }
+}
+
+/// Horizontal or vertical segment with pointed ends, `hw` pixels to each side of its axis
+fn bar<C: Canvas>(c: &mut C, p0: (i32, i32), p1: (i32, i32), hw: i32, color: Color) {
+    if hw == 0 || p0 == p1 {
+        return line(c, p0.0, p0.1, p1.0, p1.1, color);
+    }
+    let ((x0, y0), (x1, y1)) = (p0, p1);
+    if y0 == y1 {
+        let hw = max(0, (x1 - x0) / 2).min(hw);
+        let points = [
+            (x0, y0),
+            (x0 + hw, y0 - hw),
+            (x1 - hw, y0 - hw),
+            (x1, y0),
+            (x1 - hw, y0 + hw),
+            (x0 + hw, y0 + hw),
+        ];
+        fill_polygon(c, &points, color);
+    } else {
+        let hw = max(0, (y1 - y0) / 2).min(hw);
+        let points = [
+            (x0, y0),
+            (x0 + hw, y0 + hw),
+            (x0 + hw, y1 - hw),
+            (x0, y1),
+            (x0 - hw, y1 - hw),
+            (x0 - hw, y0 + hw),
+        ];
+        fill_polygon(c, &points, color);
+    }
+}
+
+/// Diagonal segment `t` pixels wide
+fn diagonal<C: Canvas>(c: &mut C, p0: (i32, i32), p1: (i32, i32), t: i32, color: Color) {
+    let ((x0, y0), (x1, y1)) = (p0, p1);
+    if (x1 - x0).abs() < 1 || (y1 - y0).abs() < 1 {
+        return;
+    }
+    if t <= 1 {
+        return line(c, x0, y0, x1, y1, color);
+    }
+    let s = (x1 - x0).signum() * (t - 1);
+    fill_polygon(c, &[(x0, y0), (x0 + s, y0), (x1, y1), (x1 - s, y1)], color);
+}
+
+/// Format a fixed-point number with `frac` decimal places, such as 237 with
+/// one place for 23.7, into `buf`, rounded to `decimals` places.
+/// Both may be up to 9 places.
+pub fn format_fixed(buf: &mut [u8], value: i32, frac: u8, decimals: u8) -> Result<&str, OutOfRange> {
+    if frac > 9 || decimals > 9 {
+        return Err(OutOfRange);
+    }
+    let mut v = value as i64;
+    if decimals < frac {
+        let div = 10i64.pow((frac - decimals) as u32);
+        v = if v < 0 { (v - div / 2) / div } else { (v + div / 2) / div };
+    } else {
+        v *= 10i64.pow((decimals - frac) as u32);
+    }
+
+    // Digits from the last, at least one before the decimal point
+    let mut digits = [0u8; 20];
+    let mut n = 0;
+    let mut u = v.unsigned_abs();
+    while n <= decimals as usize || u != 0 {
+        digits[n] = b'0' + (u % 10) as u8;
+        u /= 10;
+        n += 1;
+    }
+
+    let len = (v < 0) as usize + n + (decimals > 0) as usize;
+    if buf.len() < len {
+        return Err(OutOfRange);
+    }
+    let mut i = 0;
+    if v < 0 {
+        buf[i] = b'-';
+        i += 1;
+    }
+    for k in (0..n).rev() {
+        buf[i] = digits[k];
+        i += 1;
+        if k == decimals as usize && k != 0 {
+            buf[i] = b'.';
+            i += 1;
+        }
+    }
+    Ok(::core::str::from_utf8(&buf[..len]).unwrap_or(""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_point() {
+        let mut buf = [0; 12];
+        assert_eq!(format_fixed(&mut buf, 237, 1, 1), Ok("23.7"));
+        assert_eq!(format_fixed(&mut buf, 237, 1, 0), Ok("24"));
+        assert_eq!(format_fixed(&mut buf, 5, 2, 2), Ok("0.05"));
+        assert_eq!(format_fixed(&mut buf, -5, 2, 1), Ok("-0.1"));
+        assert_eq!(format_fixed(&mut buf, -4, 2, 1), Ok("0.0"));
+        assert_eq!(format_fixed(&mut buf, 237, 1, 3), Ok("23.700"));
+        assert_eq!(format_fixed(&mut buf, -7, 0, 2), Ok("-7.00"));
+        assert_eq!(format_fixed(&mut buf, i32::MIN, 0, 0), Ok("-2147483648"));
+    }
+
+    #[test]
+    fn fixed_point_errors() {
+        assert_eq!(format_fixed(&mut [0; 3], 237, 1, 1), Err(OutOfRange));
+        assert_eq!(format_fixed(&mut [0; 4], -5, 2, 1), Ok("-0.1"));
+        assert_eq!(format_fixed(&mut [0; 3], -5, 2, 1), Err(OutOfRange));
+        assert_eq!(format_fixed(&mut [0; 12], 1, 10, 1), Err(OutOfRange));
+        assert_eq!(format_fixed(&mut [0; 12], 1, 1, 10), Err(OutOfRange));
+    }
+}
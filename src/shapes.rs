@@ -0,0 +1,284 @@
+use core::cmp::max;
+
+use canvas::{Canvas, Color};
+use primitives::{bresenham, ellipse_spans, quadrant};
+
+/// Fixed-point scale of `sin` and `cos`
+pub const ONE: i32 = 1 << 14;
+
+/// Edges of a polygon crossing one row that are taken into account when filling
+const MAX_CROSSINGS: usize = 64;
+
+/// Longest Bezier curve segment, in pixels of control polygon length
+const SEGMENT_LEN: i32 = 4;
+
+/// Most segments a Bezier curve is drawn with
+const MAX_SEGMENTS: i32 = 64;
+
+/// Sine of 0-90 degrees
+const SIN: [i16; 91] = [
+    0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563,
+    2845, 3126, 3406, 3686, 3964, 4240, 4516, 4790, 5063, 5334,
+    5604, 5872, 6138, 6402, 6664, 6924, 7182, 7438, 7692, 7943,
+    8192, 8438, 8682, 8923, 9162, 9397, 9630, 9860, 10087, 10311,
+    10531, 10749, 10963, 11174, 11381, 11585, 11786, 11982, 12176, 12365,
+    12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+    14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296,
+    15396, 15491, 15582, 15668, 15749, 15826, 15897, 15964, 16026, 16083,
+    16135, 16182, 16225, 16262, 16294, 16322, 16344, 16362, 16374, 16382,
+    16384,
+];
+
+/// Sine of `deg` degrees, scaled by `ONE`
+pub fn sin(deg: i32) -> i32 {
+    let deg = deg.rem_euclid(360);
+    if deg <= 90 {
+        SIN[deg as usize] as i32
+    } else if deg <= 180 {
+        SIN[(180 - deg) as usize] as i32
+    } else if deg <= 270 {
+        -(SIN[(deg - 180) as usize] as i32)
+    } else {
+        -(SIN[(360 - deg) as usize] as i32)
+    }
+}
+
+/// Cosine of `deg` degrees, scaled by `ONE`
+pub fn cos(deg: i32) -> i32 {
+    sin(deg + 90)
+}
+
+/// Point at `r` pixels from a center in direction `deg`. Angles are in
+/// degrees counter-clockwise from the positive x axis, as seen on the display.
+pub fn polar(cx: i32, cy: i32, r: i32, deg: i32) -> (i32, i32) {
+    (
+        cx + div_round(r as i64 * cos(deg) as i64, ONE as i64) as i32,
+        cy - div_round(r as i64 * sin(deg) as i64, ONE as i64) as i32,
+    )
+}
+
+/// Draw the part of a circle outline from angle `start` counter-clockwise to `end`
+pub fn arc<C: Canvas>(c: &mut C, cx: i32, cy: i32, r: i32, start: i32, end: i32, color: Color) {
+    if r < 0 {
+        return;
+    }
+    let sector = Sector::new(start, end);
+    quadrant(r, r, |a, b| {
+        let mut point = |dx: i32, dy: i32| {
+            if sector.contains(dx, dy) {
+                c.set_pixel(cx + dx, cy + dy, color);
+            }
+        };
+        point(a, b);
+        if a != 0 {
+            point(-a, b);
+        }
+        if b != 0 {
+            point(a, -b);
+            if a != 0 {
+                point(-a, -b);
+            }
+        }
+    });
+}
+
+/// Draw a filled pie slice from angle `start` counter-clockwise to `end`
+pub fn pie<C: Canvas>(c: &mut C, cx: i32, cy: i32, r: i32, start: i32, end: i32, color: Color) {
+    let sector = Sector::new(start, end);
+    ellipse_spans(cx, cy, r, r, |x0, x1, y| {
+        for x in x0..x1 + 1 {
+            if sector.contains(x - cx, y - cy) {
+                c.set_pixel(x, y, color);
+            }
+        }
+    });
+}
+
+/// Draw the outline of a closed polygon
+pub fn polygon<C: Canvas>(c: &mut C, points: &[(i32, i32)], color: Color) {
+    match points.len() {
+        0 => (),
+        1 => c.set_pixel(points[0].0, points[0].1, color),
+        n => for i in 0..n {
+            let (p, q) = (points[i], points[(i + 1) % n]);
+            bresenham(c, p.0, p.1, q.0, q.1, color, false);
+        },
+    }
+}
+
+/// Draw a filled polygon with the even-odd rule. Rows crossing more than
+/// 64 edges are only filled up to the 64th.
+pub fn fill_polygon<C: Canvas>(c: &mut C, points: &[(i32, i32)], color: Color) {
+    polygon_spans(points, |x0, x1, y| ::primitives::hline(c, x0, x1, y, color));
+}
+
+/// Draw a quadratic Bezier curve from `p0` to `p2` with control point `p1`
+pub fn quad_bezier<C: Canvas>(c: &mut C, p0: (i32, i32), p1: (i32, i32), p2: (i32, i32), color: Color) {
+    let n = segments(&[p0, p1, p2]);
+    curve(c, n, color, |t| {
+        // (1-t)^2 p0 + 2(1-t)t p1 + t^2 p2 with t scaled by n
+        let u = n - t;
+        let w = [u * u, 2 * u * t, t * t];
+        let den = n * n;
+        (
+            div_round(w[0] * p0.0 as i64 + w[1] * p1.0 as i64 + w[2] * p2.0 as i64, den) as i32,
+            div_round(w[0] * p0.1 as i64 + w[1] * p1.1 as i64 + w[2] * p2.1 as i64, den) as i32,
+        )
+    });
+}
+
+/// Draw a cubic Bezier curve from `p0` to `p3` with control points `p1` and `p2`
+pub fn cubic_bezier<C: Canvas>(
+    c: &mut C,
+    p0: (i32, i32),
+    p1: (i32, i32),
+    p2: (i32, i32),
+    p3: (i32, i32),
+    color: Color,
+) {
+    let n = segments(&[p0, p1, p2, p3]);
+    curve(c, n, color, |t| {
+        // (1-t)^3 p0 + 3(1-t)^2 t p1 + 3(1-t)t^2 p2 + t^3 p3 with t scaled by n
+        let u = n - t;
+        let w = [u * u * u, 3 * u * u * t, 3 * u * t * t, t * t * t];
+        let den = n * n * n;
+        let at = |i: usize, p: (i32, i32)| (w[i] * p.0 as i64, w[i] * p.1 as i64);
+        let (a, b, c, d) = (at(0, p0), at(1, p1), at(2, p2), at(3, p3));
+        (
+            div_round(a.0 + b.0 + c.0 + d.0, den) as i32,
+            div_round(a.1 + b.1 + c.1 + d.1, den) as i32,
+        )
+    });
+}
+
+/// Call `span` with the first and last column of each filled run of each row
+/// of a polygon. Rows are sampled on the pixel centers, the last row on its
+/// lower edges so it is not left out.
+pub(crate) fn polygon_spans<F>(points: &[(i32, i32)], mut span: F)
+where
+    F: FnMut(i32, i32, i32),
+{
+    if points.is_empty() {
+        return;
+    }
+    let top = points.iter().map(|p| p.1).min().unwrap_or(0);
+    let bottom = points.iter().map(|p| p.1).max().unwrap_or(0);
+    let n = points.len();
+
+    for y in top..bottom + 1 {
+        let mut xs = [0; MAX_CROSSINGS];
+        let mut count = 0;
+        for i in 0..n {
+            let (p, q) = (points[i], points[(i + 1) % n]);
+            let (lo, hi) = if p.1 < q.1 { (p, q) } else { (q, p) };
+            let crosses = if y < bottom {
+                lo.1 <= y && y < hi.1
+            } else {
+                lo.1 < y && y <= hi.1
+            };
+            if crosses && count < MAX_CROSSINGS {
+                let dx = (hi.0 - lo.0) as i64 * (y - lo.1) as i64;
+                xs[count] = lo.0 + div_round(dx, (hi.1 - lo.1) as i64) as i32;
+                count += 1;
+            }
+        }
+
+        // Insertion sort, there are few crossings per row
+        for i in 1..count {
+            let mut j = i;
+            while j > 0 && xs[j - 1] > xs[j] {
+                xs.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+        // Runs meeting at a vertex are merged so no pixel is drawn twice
+        let mut run: Option<(i32, i32)> = None;
+        for pair in xs[..count].chunks(2) {
+            if pair.len() < 2 {
+                break;
+            }
+            run = match run {
+                Some((x0, x1)) if pair[0] <= x1 => Some((x0, max(x1, pair[1]))),
+                Some((x0, x1)) => {
+                    span(x0, x1, y);
+                    Some((pair[0], pair[1]))
+                }
+                None => Some((pair[0], pair[1])),
+            };
+        }
+        if let Some((x0, x1)) = run {
+            span(x0, x1, y);
+        }
+    }
+}
+
+/// Angles from `start` counter-clockwise to `end`
+struct Sector {
+    start: (i32, i32),
+    end: (i32, i32),
+    sweep: i32,
+}
+
+impl Sector {
+    fn new(start: i32, end: i32) -> Sector {
+        let sweep = if end - start >= 360 {
+            360
+        } else {
+            (end - start).rem_euclid(360)
+        };
+        Sector {
+            start: (cos(start), sin(start)),
+            end: (cos(end), sin(end)),
+            sweep,
+        }
+    }
+
+    /// Whether the direction to a point `dx`, `dy` pixels from the center is in the sector
+    fn contains(&self, dx: i32, dy: i32) -> bool {
+        // Display rows grow downwards
+        let p = (dx, -dy);
+        let cross = |a: (i32, i32), b: (i32, i32)| a.0 as i64 * b.1 as i64 - a.1 as i64 * b.0 as i64;
+        if self.sweep >= 360 || p == (0, 0) {
+            true
+        } else if self.sweep <= 180 {
+            cross(self.start, p) >= 0 && cross(p, self.end) >= 0
+        } else {
+            !(cross(self.end, p) > 0 && cross(p, self.start) > 0)
+        }
+    }
+}
+
+/// Segments to draw a curve with, from its control polygon length
+fn segments(points: &[(i32, i32)]) -> i64 {
+    let len: i32 = points
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0).abs() + (w[1].1 - w[0].1).abs())
+        .sum();
+    (len / SEGMENT_LEN).clamp(1, MAX_SEGMENTS) as i64
+}
+
+/// Connect the points of a curve at `t` from 0 to `n` with lines, each point drawn once
+fn curve<C, F>(c: &mut C, n: i64, color: Color, at: F)
+where
+    C: Canvas,
+    F: Fn(i64) -> (i32, i32),
+{
+    let mut p = at(0);
+    for t in 1..n + 1 {
+        let q = at(t);
+        if q != p {
+            bresenham(c, p.0, p.1, q.0, q.1, color, false);
+        }
+        p = q;
+    }
+    c.set_pixel(p.0, p.1, color);
+}
+
+/// Divide rounding to nearest, for positive `d`
+fn div_round(n: i64, d: i64) -> i64 {
+    if n >= 0 {
+        (n + d / 2) / d
+    } else {
+        (n - d / 2) / d
+    }
+}
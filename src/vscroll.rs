@@ -0,0 +1,99 @@
+use hal::blocking::i2c;
+
+use cmd::Command;
+use {Error, Ssd1306};
+
+/// Rows of display RAM
+const RAM_ROWS: u8 = 64;
+
+/// Vertical scroll state. The display shows RAM from row `offset` on,
+/// wrapping at the end of RAM.
+#[derive(Clone, Copy)]
+pub(crate) struct VScroll {
+    pub(crate) offset: u8,
+    /// Pixel rows are relative to the top of the display instead of RAM
+    pub(crate) ring: bool,
+    /// Offset not yet sent to the display
+    pub(crate) pending: bool,
+}
+
+impl<I2C> Ssd1306<I2C>
+where
+    I2C: i2c::Write,
+{
+    /// Show display RAM from row `y` on, wrapping at the end of RAM, without
+    /// rewriting it. Pixels address all 64 rows of RAM, and draw writes
+    /// every page so content taller than the display can be scrolled through.
+    pub fn set_scroll_offset(&mut self, y: u8) -> Result<(), Error<I2C::Error>> {
+        if y >= RAM_ROWS {
+            return Err(Error::OutOfRange);
+        }
+        if self.vscroll.is_none() {
+            self.dirty = 0xFF;
+        }
+        self.vscroll = Some(VScroll {
+            offset: y,
+            ring: false,
+            pending: true,
+        });
+        self.apply_scroll_offset()
+    }
+
+    /// Scroll display content up by `rows`, using display RAM as a ring buffer.
+    /// Pixel rows are relative to the top of the display from the first call on.
+    /// The rows exposed at the bottom are cleared and are the only ones written
+    /// by the next draw, which also moves the display to the new offset.
+    pub fn scroll_ring(&mut self, rows: u8) -> Result<(), Error<I2C::Error>> {
+        if rows >= RAM_ROWS {
+            return Err(Error::OutOfRange);
+        }
+        if self.vscroll.is_none() {
+            self.dirty = 0xFF;
+        }
+
+        let offset = self.vscroll.map_or(0, |v| v.offset);
+        self.vscroll = Some(VScroll {
+            offset: (offset + rows) % RAM_ROWS,
+            ring: true,
+            pending: true,
+        });
+
+        let height = self.height;
+        for y in (height - rows.min(height))..height {
+            let row = self.ram_row(y);
+            let start = (row / 8) as usize * 128;
+            let bit = 1 << (row % 8);
+            for b in self.buf[start..start + 128].iter_mut() {
+                *b &= !bit;
+            }
+            self.dirty |= 1 << (row / 8);
+        }
+        Ok(())
+    }
+
+    /// Leave scroll offset and ring buffer modes, showing RAM from the start line again
+    pub fn reset_scroll_offset(&mut self) -> Result<(), Error<I2C::Error>> {
+        self.vscroll = None;
+        self.dirty = 0xFF;
+        self.send_command(Command::start_line(self.start_line)?)
+    }
+
+    /// RAM row holding pixel row `y`
+    pub(crate) fn ram_row(&self, y: u8) -> u8 {
+        match self.vscroll {
+            Some(VScroll { offset, ring: true, .. }) => (y % RAM_ROWS + offset) % RAM_ROWS,
+            _ => y,
+        }
+    }
+
+    /// Send a scroll offset changed since the last draw
+    pub(crate) fn apply_scroll_offset(&mut self) -> Result<(), Error<I2C::Error>> {
+        match self.vscroll {
+            Some(ref mut v) if v.pending => v.pending = false,
+            _ => return Ok(()),
+        }
+        let offset = self.vscroll.map_or(0, |v| v.offset);
+        let line = (self.start_line + offset) % RAM_ROWS;
+        self.send_command(Command::start_line(line)?)
+    }
+}